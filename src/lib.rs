@@ -1,24 +1,72 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, symbol_short, token, Address, Bytes,
-    BytesN, Env, IntoVal, String, Symbol, U256, Val, Vec,
+    contract, contracterror, contractevent, contractimpl, contracttype, symbol_short, token,
+    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, ToXdr, U256, Val, Vec,
 };
 use ultrahonk_soroban_verifier::{UltraHonkVerifier, PROOF_BYTES};
 
 /// 5 minutes per turn in ledger seconds
 const TURN_DURATION_SECS: u64 = 300;
 
+/// How long a closed side-channel state can be disputed with a newer, co-signed
+/// state before the recorded split becomes claimable.
+const CHANNEL_DISPUTE_SECS: u64 = 600;
+
 /// Maximum turns: 6 guesses per player = 12 turns + 1 final verification = 13
 const MAX_TURNS: u32 = 13;
 
-/// Poseidon2 Merkle root of the 5-letter word dictionary (12 653 words, depth 14).
-const MERKLE_ROOT: [u8; 32] = [
-    0x0a, 0xe4, 0xb8, 0x21, 0xbc, 0xbf, 0xcc, 0x5f,
-    0x6a, 0x3b, 0x71, 0x1a, 0x48, 0xce, 0xb8, 0xa8,
-    0x6b, 0xaa, 0xd9, 0x69, 0xd6, 0x4f, 0xb9, 0x0c,
-    0xfd, 0x2e, 0x2b, 0x36, 0x70, 0xe3, 0x7d, 0xc7,
+/// Maximum allowed gap between a mover's self-reported move timestamp and the
+/// ledger timestamp. Bounds how far a validator nudging `env.ledger().timestamp()`
+/// within consensus tolerance can distort either player's chess clock.
+const MAX_CLOCK_DRIFT_SECS: u64 = 300;
+
+/// RLN rate-limit quota: the same 6-guess-per-player budget the chess clock
+/// already enforces. Submitting a 7th share under the same nullifier in a round
+/// fully determines the degree-`RLN_MAX_GUESSES` polynomial, letting the contract
+/// recover the offending player's identity secret via Lagrange interpolation.
+const RLN_MAX_GUESSES: u32 = 6;
+
+/// BN254 (alt_bn128) scalar field modulus — all RLN share arithmetic is done mod
+/// this prime, matching the field the Poseidon2 Merkle tree and ZK circuits use.
+const BN254_SCALAR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Elo-style rating constants. The expected-score update is scaled by 1000 here
+/// to keep it in integer arithmetic.
+const RATING_K: i32 = 32;
+const DEFAULT_RATING: i32 = 1200;
+const MAX_RECENT_RESULTS: u32 = 64;
+
+const RESULT_LOSS: u32 = 0;
+const RESULT_DRAW: u32 = 1;
+const RESULT_WIN: u32 = 2;
+
+/// Sample spacing (rating points) and domain bound for `ELO_EXPECTED_TABLE`.
+const ELO_TABLE_STEP: i32 = 25;
+const ELO_TABLE_MAX_DIFF: i32 = 800;
+
+/// The logistic expected-score curve `1 / (1 + 10^(-diff/400))`, scaled by 1000
+/// and sampled every `ELO_TABLE_STEP` rating points from `-ELO_TABLE_MAX_DIFF` to
+/// `+ELO_TABLE_MAX_DIFF` (65 samples). `no_std` has no `powf`, so rather than
+/// replace the curve with a straight line, the real curve is precomputed off-chain
+/// and `expected_score_scaled` linearly interpolates between the nearest two
+/// samples — within `ELO_TABLE_STEP/2` rating points of the exact value everywhere
+/// in the sampled range, and clamped to 0/1000 beyond it.
+const ELO_EXPECTED_TABLE: [i32; 65] = [
+    10, 11, 13, 15, 17, 20, 23, 27, 31, 35, 40, 46, 53, 61, 70, 80, 91, 104, 118, 133, 151, 170,
+    192, 215, 240, 267, 297, 327, 360, 394, 429, 464, 500, 536, 571, 606, 640, 673, 703, 733, 760,
+    785, 808, 830, 849, 867, 882, 896, 909, 920, 930, 939, 947, 954, 960, 965, 969, 973, 977, 980,
+    983, 985, 987, 989, 990,
 ];
 
+/// Depth of the on-chain incremental word-dictionary Merkle tree. The dictionary
+/// root used to be frozen into a compile-time constant (12 653 words, depth 14),
+/// which meant adding or rotating words required a redeploy; it is now built up
+/// on-chain, word by word, via `insert_word`, so the depth is what's fixed instead.
+const MERKLE_TREE_DEPTH: u32 = 14;
+
 /// Game phases
 const PHASE_WAITING: u32 = 0;  // Waiting for player 2
 const PHASE_ACTIVE: u32 = 1;   // Game in progress
@@ -26,6 +74,10 @@ const PHASE_REVEAL: u32 = 2;   // Winner must reveal their word
 const PHASE_FINALIZED: u32 = 3; // Winner confirmed, ready for withdrawal
 const PHASE_DRAW: u32 = 4;      // Max turns reached, no winner
 
+/// Pot settlement modes — see `key_pot_mode`.
+const POT_MODE_WINNER_TAKE_ALL: u32 = 1;
+const POT_MODE_CHANNEL_SPLIT: u32 = 2;
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameCreated {
@@ -42,6 +94,71 @@ pub struct GameJoined {
     pub player2: Address,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameForfeited {
+    #[topic]
+    pub game_id: Address,
+    pub winner: Address,
+}
+
+/// A single off-chain move-channel state both players co-sign: the guess made on
+/// `turn` and its ZK-verified `results`, ordered by a strictly increasing `nonce`
+/// so only the latest exchanged state can settle the game. `turn` carries the same
+/// parity convention as `key_game_turn` — odd means the state was produced for
+/// player1's guess, even for player2's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveChannelState {
+    pub nonce: u64,
+    pub turn: u32,
+    pub guess_word: Bytes,
+    pub results: Bytes,
+}
+
+/// A player's persistent win/loss/draw tally and Elo-style rating.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlayerRecord {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+    pub rating: i32,
+}
+
+/// One entry in a player's bounded recent-results ring buffer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameResult {
+    pub game_id: Address,
+    pub result: u32,
+    pub timestamp: u64,
+}
+
+/// One RLN polynomial share `(x, y)` submitted alongside a guess: `x =
+/// Poseidon2(guess_word_leaf)`, `y = A(x)` for the round's degree-`RLN_MAX_GUESSES`
+/// polynomial. `RLN_MAX_GUESSES + 1` distinct shares under one nullifier
+/// fully determine `A` and let the contract recover its secret constant term.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RlnShare {
+    pub x: BytesN<32>,
+    pub y: BytesN<32>,
+}
+
+/// A co-signed side-bet channel state: `balance_p1 + balance_p2` must always equal
+/// the game's total escrow. `revocation_hash` commits to the secret that will be
+/// revealed once this `seq` is superseded by a later state, so a player who closes
+/// the channel on a stale `seq` can be caught and punished in `dispute_channel`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChannelState {
+    pub balance_p1: i128,
+    pub balance_p2: i128,
+    pub seq: u64,
+    pub revocation_hash: BytesN<32>,
+}
+
 /// Contract
 #[contract]
 pub struct TwoPlayerWordleContract;
@@ -68,6 +185,25 @@ pub enum Error {
     NotWinner = 17,
     InvalidReveal = 18,
     InvalidSessionKey = 19,
+    ChannelPubkeyNotSet = 20,
+    ChannelBalanceMismatch = 21,
+    ChannelStaleState = 22,
+    ChannelDisputeWindowClosed = 23,
+    ChannelDisputeProofInvalid = 24,
+    NoChannelPayout = 25,
+    PayoutCommitmentMismatch = 26,
+    PayoutNullifierReused = 27,
+    MoveChannelNotOpened = 28,
+    MoveChannelNotFinal = 29,
+    MoveChannelStaleState = 30,
+    MoveChannelDisputeWindowClosed = 31,
+    ClockTimestampInvalid = 32,
+    NullifierReused = 33,
+    RlnDuplicateShare = 34,
+    RlnInsufficientShares = 35,
+    NotAdmin = 36,
+    MerkleTreeFull = 37,
+    MerkleRootNotSet = 38,
 }
 
 #[contractimpl]
@@ -81,6 +217,31 @@ impl TwoPlayerWordleContract {
         symbol_short!("wc_vk")
     }
 
+    fn key_admin() -> Symbol {
+        symbol_short!("admin")
+    }
+
+    fn key_merkle_root() -> Symbol {
+        symbol_short!("mk_root")
+    }
+
+    fn key_merkle_next_idx() -> Symbol {
+        symbol_short!("mk_next")
+    }
+
+    /// Per-level "rightmost known node" cache the incremental tree needs to
+    /// recompute only the affected root path on each `insert_word`, mirroring
+    /// semaphore-rs's `PoseidonTree` filled-subtrees.
+    fn key_merkle_filled_subtrees() -> Symbol {
+        symbol_short!("mk_fill")
+    }
+
+    /// Precomputed empty-subtree hash at each level (level 0 = the zero leaf),
+    /// used to fill in the sibling when a node's right side is still empty.
+    fn key_merkle_zero_hashes() -> Symbol {
+        symbol_short!("mk_zero")
+    }
+
     fn key_game_phase(game_id: &Address) -> (Symbol, Address) {
         (symbol_short!("gm_phase"), game_id.clone())
     }
@@ -117,6 +278,23 @@ impl TwoPlayerWordleContract {
         (symbol_short!("gm_res"), game_id.clone())
     }
 
+    /// Borrows the semaphore-rs / RLN nullifier concept: `nullifier` is the circuit's
+    /// `Poseidon2(secret, round_id)` output, scoped here to the game and turn it was
+    /// submitted for, so each nullifier is accepted at most once per game turn.
+    fn key_round_nullifier(game_id: &Address, turn: u32, nullifier: &BytesN<32>) -> (Symbol, Address, u32, BytesN<32>) {
+        (symbol_short!("rnd_null"), game_id.clone(), turn, nullifier.clone())
+    }
+
+    /// RLN shares submitted under a given `Poseidon2(a1, round_id)` nullifier.
+    fn key_rln_shares(nullifier: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("rln_shr"), nullifier.clone())
+    }
+
+    /// The recovered identity secret `a0` for a nullifier, once over-quota.
+    fn key_rln_secret(nullifier: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("rln_sec"), nullifier.clone())
+    }
+
     fn key_game_winner(game_id: &Address) -> (Symbol, Address) {
         (symbol_short!("gm_win"), game_id.clone())
     }
@@ -137,6 +315,75 @@ impl TwoPlayerWordleContract {
         (symbol_short!("p2_wd"), game_id.clone())
     }
 
+    /// Which of the two mutually-exclusive ways to pay out the pot has been chosen:
+    /// `POT_MODE_WINNER_TAKE_ALL` once `withdraw` (plaintext) or `redeem_payout`
+    /// (unlinkable) has paid a finalized-game winner the full pot, or
+    /// `POT_MODE_CHANNEL_SPLIT` once either player has claimed their share via
+    /// `claim_channel_payout`. All three paths draw from the same escrow, so
+    /// whichever runs first locks out the others for the rest of the game.
+    fn key_pot_mode(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("pot_mode"), game_id.clone())
+    }
+
+    // ── Side-bet payment channel keys ────────────────────────────────────
+
+    fn key_channel_pubkey(game_id: &Address, player: &Address) -> (Symbol, Address, Address) {
+        (symbol_short!("ch_pk"), game_id.clone(), player.clone())
+    }
+
+    fn key_channel_seq(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("ch_seq"), game_id.clone())
+    }
+
+    fn key_channel_bal1(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("ch_bal1"), game_id.clone())
+    }
+
+    fn key_channel_bal2(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("ch_bal2"), game_id.clone())
+    }
+
+    /// The revocation-secret commitment of the currently closed channel state.
+    fn key_channel_revo(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("ch_revo"), game_id.clone())
+    }
+
+    fn key_move_channel_nonce(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("mcnonce"), game_id.clone())
+    }
+
+    fn key_move_channel_dispute_until(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("mcdisp"), game_id.clone())
+    }
+
+    /// Set once `claim_move_channel_settlement` has recorded stats/hub notification
+    /// for a game, so a dispute-then-settle sequence can't be counted twice.
+    fn key_move_channel_settled(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("mcstld"), game_id.clone())
+    }
+
+    fn key_channel_dispute_until(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("ch_dead"), game_id.clone())
+    }
+
+    // ── Unlinkable payout keys ────────────────────────────────────────────
+
+    /// Pedersen-style commitment to the winner's fresh payout secret, recorded in
+    /// place of paying the known player `Address` directly.
+    fn key_payout_commitment(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("po_cmt"), game_id.clone())
+    }
+
+    fn key_payout_redeemed(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("po_done"), game_id.clone())
+    }
+
+    /// Global (not per-game) nullifier registry so a redeemed payout commitment
+    /// can never be redeemed twice, regardless of which game it came from.
+    fn key_payout_nullifier(nullifier: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("po_null"), nullifier.clone())
+    }
+
     fn key_p1_time(game_id: &Address) -> (Symbol, Address) {
         (symbol_short!("p1_time"), game_id.clone())
     }
@@ -145,6 +392,12 @@ impl TwoPlayerWordleContract {
         (symbol_short!("p2_time"), game_id.clone())
     }
 
+    /// Last accepted mover-reported timestamp, used in place of the raw ledger
+    /// timestamp when deducting chess-clock time.
+    fn key_clock_timestamp(game_id: &Address) -> (Symbol, Address) {
+        (symbol_short!("clk_ts"), game_id.clone())
+    }
+
     fn key_p1_revealed(game_id: &Address) -> (Symbol, Address) {
         (symbol_short!("p1_rev"), game_id.clone())
     }
@@ -175,6 +428,18 @@ impl TwoPlayerWordleContract {
         (symbol_short!("gm_crea"), game_id.clone())
     }
 
+    fn key_player_record(player: &Address) -> (Symbol, Address) {
+        (symbol_short!("prec"), player.clone())
+    }
+
+    fn key_player_results(player: &Address) -> (Symbol, Address) {
+        (symbol_short!("presults"), player.clone())
+    }
+
+    fn key_player_results_idx(player: &Address) -> (Symbol, Address) {
+        (symbol_short!("pridx"), player.clone())
+    }
+
     fn key_session_id(game_id: &Address) -> (Symbol, Address) {
         (symbol_short!("gm_sid"), game_id.clone())
     }
@@ -255,15 +520,150 @@ impl TwoPlayerWordleContract {
         env.invoke_contract::<()>(&game_hub, &Symbol::new(env, "end_game"), args);
     }
 
-    /// Initialize the on-chain VKs at deploy time.
+    // ── Player rating ─────────────────────────────────────────────────────
+
+    fn player_rating(env: &Env, player: &Address) -> i32 {
+        env.storage()
+            .persistent()
+            .get::<_, PlayerRecord>(&Self::key_player_record(player))
+            .map(|r| r.rating)
+            .unwrap_or(DEFAULT_RATING)
+    }
+
+    /// Update both players' persistent rating/record/recent-results following a
+    /// finalized game. `winner` is `None` for a draw. Call exactly once per
+    /// finalized game, using each player's rating from *before* either update.
+    fn settle_player_stats(
+        env: &Env,
+        game_id: &Address,
+        player1: &Address,
+        player2: &Address,
+        winner: &Option<Address>,
+    ) {
+        let r1 = Self::player_rating(env, player1);
+        let r2 = Self::player_rating(env, player2);
+
+        let (result1, result2) = match winner {
+            Some(w) if w == player1 => (RESULT_WIN, RESULT_LOSS),
+            Some(_) => (RESULT_LOSS, RESULT_WIN),
+            None => (RESULT_DRAW, RESULT_DRAW),
+        };
+
+        Self::record_player_result(env, player1, r2, result1, game_id);
+        Self::record_player_result(env, player2, r1, result2, game_id);
+    }
+
+    /// Interpolated lookup into `ELO_EXPECTED_TABLE`: the expected score (scaled by
+    /// 1000) for a player rated `diff` points above their opponent.
+    fn expected_score_scaled(diff: i32) -> i32 {
+        let clamped = diff.clamp(-ELO_TABLE_MAX_DIFF, ELO_TABLE_MAX_DIFF);
+        let shifted = clamped + ELO_TABLE_MAX_DIFF;
+        let idx = (shifted / ELO_TABLE_STEP) as usize;
+        let rem = shifted % ELO_TABLE_STEP;
+
+        let lo = ELO_EXPECTED_TABLE[idx];
+        if rem == 0 || idx + 1 >= ELO_EXPECTED_TABLE.len() {
+            return lo;
+        }
+        let hi = ELO_EXPECTED_TABLE[idx + 1];
+        lo + (hi - lo) * rem / ELO_TABLE_STEP
+    }
+
+    /// Apply one player's Elo-style rating update and append to their bounded
+    /// recent-results ring buffer (capacity `MAX_RECENT_RESULTS`), overwriting the
+    /// oldest entry once full — mirrors the bounded epoch-credits-history kept for
+    /// validator vote state.
+    fn record_player_result(
+        env: &Env,
+        player: &Address,
+        opponent_rating: i32,
+        result: u32,
+        game_id: &Address,
+    ) {
+        let rec_key = Self::key_player_record(player);
+        let mut record: PlayerRecord = env.storage().persistent().get(&rec_key).unwrap_or(PlayerRecord {
+            wins: 0,
+            losses: 0,
+            draws: 0,
+            rating: DEFAULT_RATING,
+        });
+
+        match result {
+            RESULT_WIN => record.wins += 1,
+            RESULT_DRAW => record.draws += 1,
+            _ => record.losses += 1,
+        }
+
+        let score_scaled: i32 = match result {
+            RESULT_WIN => 1000,
+            RESULT_DRAW => 500,
+            _ => 0,
+        };
+        let expected_scaled = Self::expected_score_scaled(record.rating - opponent_rating);
+        record.rating += RATING_K * (score_scaled - expected_scaled) / 1000;
+
+        env.storage().persistent().set(&rec_key, &record);
+        env.storage().persistent().extend_ttl(&rec_key, 200_000, 200_000);
+
+        let results_key = Self::key_player_results(player);
+        let mut results: Vec<GameResult> = env
+            .storage()
+            .persistent()
+            .get(&results_key)
+            .unwrap_or(Vec::new(env));
+        let entry = GameResult {
+            game_id: game_id.clone(),
+            result,
+            timestamp: env.ledger().timestamp(),
+        };
+        if results.len() < MAX_RECENT_RESULTS {
+            results.push_back(entry);
+        } else {
+            let idx_key = Self::key_player_results_idx(player);
+            let next_idx: u32 = env.storage().persistent().get(&idx_key).unwrap_or(0);
+            results.set(next_idx, entry);
+            let new_idx = (next_idx + 1) % MAX_RECENT_RESULTS;
+            env.storage().persistent().set(&idx_key, &new_idx);
+            env.storage().persistent().extend_ttl(&idx_key, 200_000, 200_000);
+        }
+        env.storage().persistent().set(&results_key, &results);
+        env.storage().persistent().extend_ttl(&results_key, 200_000, 200_000);
+    }
+
+    /// Initialize the on-chain VKs and the empty word-dictionary Merkle tree at
+    /// deploy time. `admin` is the only address allowed to call `insert_word`.
     /// `vk_bytes` – verification key for the guess-result circuit.
     /// `wc_vk_bytes` – verification key for the word-commit circuit.
-    pub fn __constructor(env: Env, vk_bytes: Bytes, wc_vk_bytes: Bytes) -> Result<(), Error> {
+    pub fn __constructor(env: Env, vk_bytes: Bytes, wc_vk_bytes: Bytes, admin: Address) -> Result<(), Error> {
         env.storage().instance().set(&Self::key_vk(), &vk_bytes);
         env.storage().instance().set(&Self::key_wc_vk(), &wc_vk_bytes);
+        env.storage().instance().set(&Self::key_admin(), &admin);
+        Self::init_merkle_tree(&env);
         Ok(())
     }
 
+    /// Build the precomputed zero-hashes for an empty depth-`MERKLE_TREE_DEPTH`
+    /// tree, seed the filled-subtrees cache with them, and store the resulting
+    /// empty-tree root — the starting point `insert_word` builds the dictionary
+    /// up from.
+    fn init_merkle_tree(env: &Env) {
+        let field = Symbol::new(env, "BN254");
+        let mut zero_hashes = Vec::new(env);
+        let mut current_zero = U256::from_u128(env, 0);
+        for _ in 0..MERKLE_TREE_DEPTH {
+            zero_hashes.push_back(Self::u256_to_bytesn(env, &current_zero));
+            let mut inputs = Vec::new(env);
+            inputs.push_back(current_zero.clone());
+            inputs.push_back(current_zero.clone());
+            current_zero = env.crypto().poseidon2_hash(&inputs, field.clone());
+        }
+
+        env.storage().instance().set(&Self::key_merkle_zero_hashes(), &zero_hashes);
+        env.storage().instance().set(&Self::key_merkle_filled_subtrees(), &zero_hashes);
+        env.storage().instance().set(&Self::key_merkle_next_idx(), &0u32);
+        env.storage().instance().set(&Self::key_merkle_root(), &Self::u256_to_bytesn(env, &current_zero));
+    }
+
     /// Player 1 creates a new game with their commitment and escrow.
     /// A word-commit ZK proof must be provided to prove the committed word
     /// is in the dictionary.
@@ -474,6 +874,12 @@ impl TwoPlayerWordleContract {
         env.storage().temporary().set(&dead_key, &deadline);
         env.storage().temporary().extend_ttl(&dead_key, 5000, 5000);
 
+        // Seed the validated-timestamp baseline from the ledger; subsequent moves
+        // must advance it via the mover's own signed-in-order timestamp.
+        let ts_key = Self::key_clock_timestamp(&game_id);
+        env.storage().temporary().set(&ts_key, &env.ledger().timestamp());
+        env.storage().temporary().extend_ttl(&ts_key, 5000, 5000);
+
         // Notify game hub that game has started
         let p1_for_hub: Address = env
             .storage()
@@ -496,13 +902,23 @@ impl TwoPlayerWordleContract {
     /// Turn 1: P1 just submits their guess (no ZK proof needed)
     /// Turn 2+: Current player provides ZK proof of opponent's previous guess + submits new guess
     /// Turn 13: P1 verify-only (no new guess)
+    ///
+    /// `move_timestamp` is the mover's own locally-observed time, used instead of the
+    /// raw ledger timestamp to deduct chess-clock time — this bounds how much a
+    /// validator nudging `env.ledger().timestamp()` within consensus tolerance can
+    /// distort either player's clock. It must be non-decreasing relative to the last
+    /// accepted value and within `MAX_CLOCK_DRIFT_SECS` of the ledger timestamp.
     pub fn submit_turn(
         env: Env,
         game_id: Address,
         caller: Address,
+        move_timestamp: u64,
         my_guess_word: Bytes,          // empty on turn 13
         path_elements: Vec<BytesN<32>>, // Merkle proof for my_guess_word
         path_indices: Vec<u32>,
+        rln_share_x: BytesN<32>,  // RLN share x = Poseidon2(guess_word_leaf); zero on turn 13
+        rln_share_y: BytesN<32>,  // RLN share y = A(x); zero on turn 13
+        rln_nullifier: BytesN<32>, // Poseidon2(a1, round_id); zero on turn 13
         public_inputs: Bytes,           // empty on turn 1
         proof_bytes: Bytes,             // empty on turn 1
     ) -> Result<(), Error> {
@@ -520,6 +936,19 @@ impl TwoPlayerWordleContract {
             return Err(Error::WrongPhase);
         }
 
+        // Validate the mover's self-reported timestamp: must not go backwards and
+        // must stay within the allowed drift of the ledger's own clock.
+        let ts_key = Self::key_clock_timestamp(&game_id);
+        let last_timestamp: u64 = env.storage().temporary().get(&ts_key).unwrap_or(0);
+        let ledger_now = env.ledger().timestamp();
+        if move_timestamp < last_timestamp
+            || move_timestamp.abs_diff(ledger_now) > MAX_CLOCK_DRIFT_SECS
+        {
+            return Err(Error::ClockTimestampInvalid);
+        }
+        env.storage().temporary().set(&ts_key, &move_timestamp);
+        env.storage().temporary().extend_ttl(&ts_key, 5000, 5000);
+
         // Check deadline hasn't passed
         let dead_key = Self::key_game_deadline(&game_id);
         let deadline: u64 = env
@@ -527,7 +956,7 @@ impl TwoPlayerWordleContract {
             .temporary()
             .get(&dead_key)
             .ok_or(Error::NoActiveGame)?;
-        if env.ledger().timestamp() > deadline {
+        if move_timestamp > deadline {
             return Err(Error::GameExpired);
         }
 
@@ -568,7 +997,15 @@ impl TwoPlayerWordleContract {
         // Turn 1: P1 just submits their guess
         if turn == 1 {
             // Validate guess word via Merkle proof
-            Self::do_verify_guess(&env, &my_guess_word, &path_elements, &path_indices)?;
+            Self::do_verify_guess(
+                &env,
+                &my_guess_word,
+                &path_elements,
+                &path_indices,
+                &rln_share_x,
+                &rln_share_y,
+                &rln_nullifier,
+            )?;
 
             // Store the guess
             let guess_key = Self::key_game_guess(&game_id);
@@ -580,12 +1017,12 @@ impl TwoPlayerWordleContract {
 
             // Update chess clock: P1's time remains, set deadline for P2
             let p1_time_key = Self::key_p1_time(&game_id);
-            let p1_remaining = TURN_DURATION_SECS - (env.ledger().timestamp() - (deadline - TURN_DURATION_SECS));
+            let p1_remaining = TURN_DURATION_SECS - (move_timestamp - (deadline - TURN_DURATION_SECS));
             env.storage().temporary().set(&p1_time_key, &p1_remaining);
 
             let p2_time_key = Self::key_p2_time(&game_id);
             let p2_remaining: u64 = env.storage().temporary().get(&p2_time_key).unwrap_or(TURN_DURATION_SECS);
-            let new_deadline = env.ledger().timestamp() + p2_remaining;
+            let new_deadline = move_timestamp + p2_remaining;
             env.storage().temporary().set(&dead_key, &new_deadline);
 
             return Ok(());
@@ -629,6 +1066,16 @@ impl TwoPlayerWordleContract {
         // Verify ZK proof
         Self::do_verify_proof(&env, &public_inputs, &proof_bytes)?;
 
+        // Reject replay of an already-seen nullifier for this game/turn: only valid
+        // after the proof itself verifies, so a forged or stale proof can't burn one.
+        let nullifier = Self::extract_nullifier_from_pi(&env, &public_inputs);
+        let null_key = Self::key_round_nullifier(&game_id, turn, &nullifier);
+        if env.storage().persistent().has(&null_key) {
+            return Err(Error::NullifierReused);
+        }
+        env.storage().persistent().set(&null_key, &true);
+        env.storage().persistent().extend_ttl(&null_key, 20000, 20000);
+
         // Extract results (5 values starting at offset 192)
         let mut all_correct = true;
         let mut results = Bytes::new(&env);
@@ -673,12 +1120,21 @@ impl TwoPlayerWordleContract {
 
             // Notify game hub of draw (no winner)
             Self::call_end_game(&env, &game_id, false);
+            Self::settle_player_stats(&env, &game_id, &player1, &player2, &None);
 
             return Ok(());
         }
 
         // Continue playing: validate and store my new guess
-        Self::do_verify_guess(&env, &my_guess_word, &path_elements, &path_indices)?;
+        Self::do_verify_guess(
+            &env,
+            &my_guess_word,
+            &path_elements,
+            &path_indices,
+            &rln_share_x,
+            &rln_share_y,
+            &rln_nullifier,
+        )?;
         env.storage().temporary().set(&guess_key, &my_guess_word);
 
         // Advance turn
@@ -696,7 +1152,7 @@ impl TwoPlayerWordleContract {
             Self::key_p2_time(&game_id)
         };
 
-        let my_remaining = deadline.saturating_sub(env.ledger().timestamp());
+        let my_remaining = deadline.saturating_sub(move_timestamp);
         env.storage().temporary().set(&my_time_key, &my_remaining);
 
         let opponent_remaining: u64 = env
@@ -704,7 +1160,7 @@ impl TwoPlayerWordleContract {
             .temporary()
             .get(&opponent_time_key)
             .unwrap_or(TURN_DURATION_SECS);
-        let new_deadline = env.ledger().timestamp() + opponent_remaining;
+        let new_deadline = move_timestamp + opponent_remaining;
         env.storage().temporary().set(&dead_key, &new_deadline);
 
         Ok(())
@@ -720,6 +1176,7 @@ impl TwoPlayerWordleContract {
         reveal_word: Bytes,
         public_inputs: Bytes,
         proof_bytes: Bytes,
+        payout_commitment: Option<BytesN<32>>,
     ) -> Result<(), Error> {
         // Resolve caller: may be player directly or their session key
         let actual_caller = Self::resolve_caller_simple(&env, &game_id, &caller);
@@ -783,13 +1240,104 @@ impl TwoPlayerWordleContract {
         // Finalize the game.
         env.storage().temporary().set(&phase_key, &PHASE_FINALIZED);
 
+        // Opt in to an unlinkable payout: record a commitment to a fresh secret
+        // instead of relying solely on the plaintext winner address for `withdraw`.
+        // `redeem_payout` later pays this out to any address that proves knowledge
+        // of the committed secret, breaking the link to the funded player account.
+        if let Some(commitment) = payout_commitment {
+            let cmt_key = Self::key_payout_commitment(&game_id);
+            env.storage().temporary().set(&cmt_key, &commitment);
+            env.storage().temporary().extend_ttl(&cmt_key, 5000, 5000);
+        }
+
         // Notify game hub that game ended
         let player1_won = actual_caller == player1;
         Self::call_end_game(&env, &game_id, player1_won);
 
+        let p2_key = Self::key_game_p2(&game_id);
+        let player2: Address = env.storage().temporary().get(&p2_key).ok_or(Error::NoActiveGame)?;
+        Self::settle_player_stats(&env, &game_id, &player1, &player2, &Some(winner));
+
         Ok(())
     }
 
+    /// Redeem an unlinkable payout opted into via `reveal_word`'s `payout_commitment`.
+    /// `public_inputs`/`proof_bytes` prove knowledge of the secret behind `commitment`
+    /// using the same `UltraHonkVerifier` path as the rest of the contract; the payout
+    /// goes to `payout_to`, which need not be related to either funded player address.
+    /// `nullifier` is recorded globally so the same commitment can't be redeemed twice.
+    pub fn redeem_payout(
+        env: Env,
+        game_id: Address,
+        commitment: BytesN<32>,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+        nullifier: BytesN<32>,
+        payout_to: Address,
+    ) -> Result<i128, Error> {
+        payout_to.require_auth();
+
+        let stored_commitment: BytesN<32> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_payout_commitment(&game_id))
+            .ok_or(Error::NoActiveGame)?;
+        if stored_commitment != commitment {
+            return Err(Error::PayoutCommitmentMismatch);
+        }
+
+        let redeemed_key = Self::key_payout_redeemed(&game_id);
+        let already_redeemed: bool = env.storage().temporary().get(&redeemed_key).unwrap_or(false);
+        if already_redeemed {
+            return Err(Error::AlreadyWithdrawn);
+        }
+
+        // Shares `key_pot_mode` with `withdraw`/`claim_channel_payout` so this
+        // escrow can't be drained twice through two different payout paths;
+        // whichever runs first locks out the others.
+        let mode_key = Self::key_pot_mode(&game_id);
+        let pot_mode: u32 = env.storage().temporary().get(&mode_key).unwrap_or(0);
+        if pot_mode != 0 {
+            return Err(Error::AlreadyWithdrawn);
+        }
+
+        let null_key = Self::key_payout_nullifier(&nullifier);
+        if env.storage().persistent().has(&null_key) {
+            return Err(Error::PayoutNullifierReused);
+        }
+
+        let commitment_from_pi = Self::extract_commitment_from_pi(&env, &public_inputs);
+        if commitment != commitment_from_pi {
+            return Err(Error::PayoutCommitmentMismatch);
+        }
+        Self::do_verify_proof(&env, &public_inputs, &proof_bytes)?;
+
+        env.storage().persistent().set(&null_key, &true);
+        env.storage().persistent().extend_ttl(&null_key, 20000, 20000);
+
+        let amt_key = Self::key_escrow_amount(&game_id);
+        let escrow_per_player: i128 = env.storage().temporary().get(&amt_key).unwrap_or(0);
+        let payout = escrow_per_player * 2;
+
+        if payout > 0 {
+            let token_key = Self::key_escrow_token(&game_id);
+            let token_addr: Address = env
+                .storage()
+                .temporary()
+                .get(&token_key)
+                .ok_or(Error::NoActiveGame)?;
+            let token_client = token::TokenClient::new(&env, &token_addr);
+            token_client.transfer(&env.current_contract_address(), &payout_to, &payout);
+        }
+
+        env.storage().temporary().set(&redeemed_key, &true);
+        env.storage().temporary().extend_ttl(&redeemed_key, 5000, 5000);
+        env.storage().temporary().set(&mode_key, &POT_MODE_WINNER_TAKE_ALL);
+        env.storage().temporary().extend_ttl(&mode_key, 5000, 5000);
+
+        Ok(payout)
+    }
+
     /// In a draw, each player reveals their word to prove it matches their commitment.
     /// Dictionary membership was already verified at game creation via word-commit proof.
     pub fn reveal_word_draw(
@@ -915,9 +1463,9 @@ impl TwoPlayerWordleContract {
         // Caller must be one of the players
         let caller_is_p1 = actual_caller == player1;
         let opponent = if caller_is_p1 {
-            player2
+            player2.clone()
         } else if actual_caller == player2 {
-            player1
+            player1.clone()
         } else {
             return Err(Error::WrongPlayer);
         };
@@ -930,20 +1478,15 @@ impl TwoPlayerWordleContract {
 
         // Notify game hub (if caller resigned, they didn't win)
         Self::call_end_game(&env, &game_id, !caller_is_p1);
+        Self::settle_player_stats(&env, &game_id, &player1, &player2, &Some(opponent));
 
         Ok(())
     }
 
-    /// Claim timeout: if the opponent didn't play in time, you win.
-    /// Bundles timeout claim + word reveal into a single transaction.
-    pub fn claim_timeout(
-        env: Env,
-        game_id: Address,
-        caller: Address,
-        reveal_word: Bytes,
-        public_inputs: Bytes,
-        proof_bytes: Bytes,
-    ) -> Result<(), Error> {
+    /// Claim timeout: if the opponent didn't play in time, you win by forfeit.
+    /// The stalling player's secret word is never needed to resolve this, so it
+    /// skips the `PHASE_REVEAL` step entirely and finalizes immediately.
+    pub fn claim_timeout(env: Env, game_id: Address, caller: Address) -> Result<(), Error> {
         // Resolve caller: may be player directly or their session key
         let actual_caller = Self::resolve_caller_simple(&env, &game_id, &caller);
 
@@ -959,7 +1502,9 @@ impl TwoPlayerWordleContract {
             return Err(Error::WrongPhase);
         }
 
-        // Check deadline has actually passed
+        // Check deadline has actually passed. `deadline` was computed in `submit_turn`
+        // from the mover's validated timestamp; it is compared here against the
+        // current ledger timestamp.
         let dead_key = Self::key_game_deadline(&game_id);
         let deadline: u64 = env
             .storage()
@@ -984,56 +1529,37 @@ impl TwoPlayerWordleContract {
             .get(&p2_key)
             .ok_or(Error::NoActiveGame)?;
 
-        // The person whose turn it is timed out
+        // The person whose turn it is timed out; the other player wins by forfeit.
         let turn_key = Self::key_game_turn(&game_id);
         let turn: u32 = env
             .storage()
             .temporary()
             .get(&turn_key)
             .ok_or(Error::NoActiveGame)?;
-        let timed_out_player = if turn % 2 == 1 { &player1 } else { &player2 };
+        // The opponent of the timed-out player always wins — never the caller
+        // directly, so a third party can't self-declare as winner.
+        let opponent = if turn % 2 == 1 { &player2 } else { &player1 };
 
-        // Caller must be the opponent of the timed-out player
-        if &actual_caller == timed_out_player {
+        if &actual_caller != opponent {
             return Err(Error::WrongPlayer);
         }
 
-        // Get caller's commitment and verify the reveal proof
-        let caller_commitment: BytesN<32> = if actual_caller == player1 {
-            let c1_key = Self::key_game_c1(&game_id);
-            env.storage()
-                .temporary()
-                .get(&c1_key)
-                .ok_or(Error::NoActiveGame)?
-        } else {
-            let c2_key = Self::key_game_c2(&game_id);
-            env.storage()
-                .temporary()
-                .get(&c2_key)
-                .ok_or(Error::NoActiveGame)?
-        };
-
-        // Verify reveal: commitment + letters + all-correct results + ZK proof
-        Self::do_verify_reveal(&env, &caller_commitment, &reveal_word, &public_inputs, &proof_bytes)?;
-
-        // Store revealed word
-        let word_key = if actual_caller == player1 {
-            Self::key_p1_word(&game_id)
-        } else {
-            Self::key_p2_word(&game_id)
-        };
-        env.storage().temporary().set(&word_key, &reveal_word);
-        env.storage().temporary().extend_ttl(&word_key, 5000, 5000);
-
         // Set winner and finalize
         let win_key = Self::key_game_winner(&game_id);
-        env.storage().temporary().set(&win_key, &actual_caller);
+        env.storage().temporary().set(&win_key, opponent);
         env.storage().temporary().extend_ttl(&win_key, 5000, 5000);
         env.storage().temporary().set(&phase_key, &PHASE_FINALIZED);
 
         // Notify game hub
-        let player1_won = actual_caller == player1;
+        let player1_won = opponent == &player1;
         Self::call_end_game(&env, &game_id, player1_won);
+        Self::settle_player_stats(&env, &game_id, &player1, &player2, &Some(opponent.clone()));
+
+        GameForfeited {
+            game_id: game_id.clone(),
+            winner: opponent.clone(),
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -1104,6 +1630,25 @@ impl TwoPlayerWordleContract {
         let payout: i128;
 
         if phase == PHASE_FINALIZED {
+            // If the winner opted into an unlinkable payout and it was already
+            // redeemed via `redeem_payout`, this escrow is spent.
+            let payout_redeemed: bool = env
+                .storage()
+                .temporary()
+                .get(&Self::key_payout_redeemed(&game_id))
+                .unwrap_or(false);
+            if payout_redeemed {
+                return Err(Error::AlreadyWithdrawn);
+            }
+
+            // The side-bet channel draws from this same pot via `claim_channel_payout`;
+            // whichever payout path runs first locks out the other.
+            let mode_key = Self::key_pot_mode(&game_id);
+            let pot_mode: u32 = env.storage().temporary().get(&mode_key).unwrap_or(0);
+            if pot_mode == POT_MODE_CHANNEL_SPLIT {
+                return Err(Error::AlreadyWithdrawn);
+            }
+
             // Only winner can withdraw, gets full pot
             let win_key = Self::key_game_winner(&game_id);
             let winner: Address = env
@@ -1115,6 +1660,8 @@ impl TwoPlayerWordleContract {
                 return Err(Error::NotWinner);
             }
             payout = escrow_per_player * 2;
+            env.storage().temporary().set(&mode_key, &POT_MODE_WINNER_TAKE_ALL);
+            env.storage().temporary().extend_ttl(&mode_key, 5000, 5000);
         } else {
             // Draw: player must have revealed their word to withdraw
             let rev_key = if is_p1 {
@@ -1142,34 +1689,537 @@ impl TwoPlayerWordleContract {
         Ok(payout)
     }
 
-    // ── Query functions ──────────────────────────────────────────────────
+    // ── Side-bet payment channel ─────────────────────────────────────────
+    // Lets the two players escalate a side wager turn-by-turn by exchanging
+    // signed channel states off-chain, touching the contract only to close or
+    // dispute. `balance_p1 + balance_p2` always equals the game's total escrow.
 
-    pub fn get_game_phase(env: Env, game_id: Address) -> u32 {
-        let key = Self::key_game_phase(&game_id);
-        env.storage().temporary().get(&key).unwrap_or(255)
-    }
+    /// Register the P-256 key a player will use to sign off-chain channel states.
+    pub fn register_channel_pubkey(
+        env: Env,
+        game_id: Address,
+        player: Address,
+        pubkey: BytesN<65>,
+    ) -> Result<(), Error> {
+        player.require_auth();
 
-    pub fn get_game_turn(env: Env, game_id: Address) -> u32 {
-        let key = Self::key_game_turn(&game_id);
-        env.storage().temporary().get(&key).unwrap_or(0)
-    }
+        let p1_key = Self::key_game_p1(&game_id);
+        let p2_key = Self::key_game_p2(&game_id);
+        let is_p1 = env
+            .storage()
+            .temporary()
+            .get::<_, Address>(&p1_key)
+            .map(|p| p == player)
+            .unwrap_or(false);
+        let is_p2 = env
+            .storage()
+            .temporary()
+            .get::<_, Address>(&p2_key)
+            .map(|p| p == player)
+            .unwrap_or(false);
+        if !is_p1 && !is_p2 {
+            return Err(Error::WrongPlayer);
+        }
 
-    pub fn get_game_deadline(env: Env, game_id: Address) -> u64 {
-        let key = Self::key_game_deadline(&game_id);
-        env.storage().temporary().get(&key).unwrap_or(0)
+        let pk_key = Self::key_channel_pubkey(&game_id, &player);
+        env.storage().temporary().set(&pk_key, &pubkey);
+        env.storage().temporary().extend_ttl(&pk_key, 5000, 5000);
+        Ok(())
     }
 
-    pub fn get_last_guess(env: Env, game_id: Address) -> Bytes {
-        let key = Self::key_game_guess(&game_id);
-        env.storage().temporary().get(&key).unwrap_or(Bytes::new(&env))
+    /// SHA-256 digest of a channel state, the message both players' signatures cover.
+    fn channel_state_digest(env: &Env, game_id: &Address, state: &ChannelState) -> BytesN<32> {
+        let mut msg = Bytes::new(env);
+        msg.append(&game_id.to_xdr(env));
+        msg.append(&Bytes::from_slice(env, &state.balance_p1.to_be_bytes()));
+        msg.append(&Bytes::from_slice(env, &state.balance_p2.to_be_bytes()));
+        msg.append(&Bytes::from_slice(env, &state.seq.to_be_bytes()));
+        msg.append(&Bytes::from(state.revocation_hash.clone()));
+        env.crypto().sha256(&msg).to_bytes()
     }
 
-    pub fn get_last_results(env: Env, game_id: Address) -> Bytes {
-        let key = Self::key_game_results(&game_id);
-        env.storage().temporary().get(&key).unwrap_or(Bytes::new(&env))
-    }
+    /// Close the channel on the highest-`seq` state both players co-signed, recording
+    /// the split as claimable once the dispute window passes.
+    pub fn close_channel(
+        env: Env,
+        game_id: Address,
+        state: ChannelState,
+        sig_p1: BytesN<64>,
+        sig_p2: BytesN<64>,
+    ) -> Result<(), Error> {
+        let p1_key = Self::key_game_p1(&game_id);
+        let player1: Address = env.storage().temporary().get(&p1_key).ok_or(Error::NoActiveGame)?;
+        let p2_key = Self::key_game_p2(&game_id);
+        let player2: Address = env.storage().temporary().get(&p2_key).ok_or(Error::NoActiveGame)?;
 
-    pub fn get_player1(env: Env, game_id: Address) -> Address {
+        let pk1: BytesN<65> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_pubkey(&game_id, &player1))
+            .ok_or(Error::ChannelPubkeyNotSet)?;
+        let pk2: BytesN<65> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_pubkey(&game_id, &player2))
+            .ok_or(Error::ChannelPubkeyNotSet)?;
+
+        let digest = Self::channel_state_digest(&env, &game_id, &state);
+        env.crypto().secp256r1_verify(&pk1, &digest, &sig_p1);
+        env.crypto().secp256r1_verify(&pk2, &digest, &sig_p2);
+
+        let amt_key = Self::key_escrow_amount(&game_id);
+        let escrow_per_player: i128 = env.storage().temporary().get(&amt_key).unwrap_or(0);
+        if state.balance_p1 + state.balance_p2 != escrow_per_player * 2 {
+            return Err(Error::ChannelBalanceMismatch);
+        }
+
+        let seq_key = Self::key_channel_seq(&game_id);
+        let current_seq: u64 = env.storage().temporary().get(&seq_key).unwrap_or(0);
+        if state.seq <= current_seq {
+            return Err(Error::ChannelStaleState);
+        }
+
+        env.storage().temporary().set(&seq_key, &state.seq);
+        env.storage().temporary().extend_ttl(&seq_key, 5000, 5000);
+        env.storage().temporary().set(&Self::key_channel_bal1(&game_id), &state.balance_p1);
+        env.storage().temporary().set(&Self::key_channel_bal2(&game_id), &state.balance_p2);
+        env.storage().temporary().set(&Self::key_channel_revo(&game_id), &state.revocation_hash);
+
+        let dispute_until = env.ledger().timestamp() + CHANNEL_DISPUTE_SECS;
+        let dead_key = Self::key_channel_dispute_until(&game_id);
+        env.storage().temporary().set(&dead_key, &dispute_until);
+        env.storage().temporary().extend_ttl(&dead_key, 5000, 5000);
+
+        Ok(())
+    }
+
+    /// Punish a player who closed on a stale state: `revocation_secret` must hash to
+    /// the revocation commitment the closer published for the `seq` they closed on.
+    /// If it matches, that secret could only be known because the closer had already
+    /// moved past that `seq` off-chain, proving the closed state was stale — the
+    /// caller takes the entire pot. The caller must be one of the two players; the
+    /// contract does not record which player closed the channel, so the closer is
+    /// not excluded.
+    pub fn dispute_channel(
+        env: Env,
+        game_id: Address,
+        caller: Address,
+        revocation_secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        let actual_caller = Self::resolve_caller_simple(&env, &game_id, &caller);
+
+        let dead_key = Self::key_channel_dispute_until(&game_id);
+        let dispute_until: u64 = env.storage().temporary().get(&dead_key).ok_or(Error::NoActiveGame)?;
+        if env.ledger().timestamp() > dispute_until {
+            return Err(Error::ChannelDisputeWindowClosed);
+        }
+
+        let stored_revo: BytesN<32> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_revo(&game_id))
+            .ok_or(Error::NoActiveGame)?;
+
+        let secret_bytes: Bytes = revocation_secret.into();
+        let revealed_hash = env.crypto().sha256(&secret_bytes).to_bytes();
+        if revealed_hash != stored_revo {
+            return Err(Error::ChannelDisputeProofInvalid);
+        }
+
+        let p1_key = Self::key_game_p1(&game_id);
+        let player1: Address = env.storage().temporary().get(&p1_key).ok_or(Error::NoActiveGame)?;
+        let p2_key = Self::key_game_p2(&game_id);
+        let player2: Address = env.storage().temporary().get(&p2_key).ok_or(Error::NoActiveGame)?;
+
+        // Only one of the two players can win a dispute — otherwise any address that
+        // learns the revocation preimage (it's revealed on-chain once the closer uses
+        // it) could name itself winner, and since `withdraw`/`claim_channel_payout`
+        // both require the caller to be a player, the pot would then be unclaimable.
+        if actual_caller != player1 && actual_caller != player2 {
+            return Err(Error::WrongPlayer);
+        }
+
+        let phase_key = Self::key_game_phase(&game_id);
+        env.storage().temporary().set(&phase_key, &PHASE_FINALIZED);
+        let win_key = Self::key_game_winner(&game_id);
+        env.storage().temporary().set(&win_key, &actual_caller);
+        env.storage().temporary().extend_ttl(&win_key, 5000, 5000);
+
+        let player1_won = actual_caller == player1;
+        Self::call_end_game(&env, &game_id, player1_won);
+        Self::settle_player_stats(&env, &game_id, &player1, &player2, &Some(actual_caller));
+
+        Ok(())
+    }
+
+    /// Pay out the closed channel split once the dispute window has passed
+    /// undisputed. Shares the same withdrawn flags as `withdraw` so the escrow
+    /// can't be drained twice through the two payout paths, and shares `key_pot_mode`
+    /// so a finalized-game winner who already took the whole pot via `withdraw`
+    /// can't also be matched by a channel claim on the same escrow.
+    pub fn claim_channel_payout(env: Env, game_id: Address, caller: Address) -> Result<i128, Error> {
+        let actual_caller = Self::resolve_caller_simple(&env, &game_id, &caller);
+
+        let dead_key = Self::key_channel_dispute_until(&game_id);
+        let dispute_until: u64 = env.storage().temporary().get(&dead_key).ok_or(Error::NoChannelPayout)?;
+        if env.ledger().timestamp() <= dispute_until {
+            return Err(Error::ChannelDisputeWindowClosed);
+        }
+
+        let mode_key = Self::key_pot_mode(&game_id);
+        let pot_mode: u32 = env.storage().temporary().get(&mode_key).unwrap_or(0);
+        if pot_mode == POT_MODE_WINNER_TAKE_ALL {
+            return Err(Error::AlreadyWithdrawn);
+        }
+
+        let p1_key = Self::key_game_p1(&game_id);
+        let player1: Address = env.storage().temporary().get(&p1_key).ok_or(Error::NoActiveGame)?;
+        let p2_key = Self::key_game_p2(&game_id);
+        let player2: Address = env.storage().temporary().get(&p2_key).ok_or(Error::NoActiveGame)?;
+
+        let is_p1 = actual_caller == player1;
+        let is_p2 = actual_caller == player2;
+        if !is_p1 && !is_p2 {
+            return Err(Error::WrongPlayer);
+        }
+
+        let wd_key = if is_p1 {
+            Self::key_p1_withdrawn(&game_id)
+        } else {
+            Self::key_p2_withdrawn(&game_id)
+        };
+        let already_withdrawn: bool = env.storage().temporary().get(&wd_key).unwrap_or(false);
+        if already_withdrawn {
+            return Err(Error::AlreadyWithdrawn);
+        }
+
+        env.storage().temporary().set(&mode_key, &POT_MODE_CHANNEL_SPLIT);
+        env.storage().temporary().extend_ttl(&mode_key, 5000, 5000);
+
+        let payout: i128 = if is_p1 {
+            env.storage().temporary().get(&Self::key_channel_bal1(&game_id)).unwrap_or(0)
+        } else {
+            env.storage().temporary().get(&Self::key_channel_bal2(&game_id)).unwrap_or(0)
+        };
+
+        if payout > 0 {
+            let token_key = Self::key_escrow_token(&game_id);
+            let token_addr: Address = env.storage().temporary().get(&token_key).ok_or(Error::NoActiveGame)?;
+            let token_client = token::TokenClient::new(&env, &token_addr);
+            token_client.transfer(&env.current_contract_address(), &actual_caller, &payout);
+        }
+
+        env.storage().temporary().set(&wd_key, &true);
+        env.storage().temporary().extend_ttl(&wd_key, 5000, 5000);
+
+        Ok(payout)
+    }
+
+    /// SHA-256 digest of a move-channel state, the message both players' signatures
+    /// cover when settling a game played off-chain via `open_move_channel`.
+    fn move_channel_state_digest(env: &Env, game_id: &Address, state: &MoveChannelState) -> BytesN<32> {
+        let mut msg = Bytes::new(env);
+        msg.append(&game_id.to_xdr(env));
+        msg.append(&Bytes::from_slice(env, &state.nonce.to_be_bytes()));
+        msg.append(&Bytes::from_slice(env, &state.turn.to_be_bytes()));
+        msg.append(&state.guess_word);
+        msg.append(&state.results);
+        env.crypto().sha256(&msg).to_bytes()
+    }
+
+    /// The winner (or draw) a settled/disputed move-channel state encodes. `turn`
+    /// names the guesser via the same parity `submit_turn` uses; all-`2` results
+    /// mean that guesser won, an exhausted turn count with no win is a draw, and
+    /// anything else isn't a terminal state a channel is allowed to settle on.
+    fn move_channel_winner(
+        state: &MoveChannelState,
+        player1: &Address,
+        player2: &Address,
+    ) -> Result<Option<Address>, Error> {
+        let all_correct = state.results.len() == 5
+            && (0..5u32).all(|i| state.results.get(i).unwrap_or(0) == 2);
+        if all_correct {
+            let guesser = if state.turn % 2 == 1 { player1 } else { player2 };
+            return Ok(Some(guesser.clone()));
+        }
+        if state.turn >= MAX_TURNS {
+            return Ok(None);
+        }
+        Err(Error::MoveChannelNotFinal)
+    }
+
+    /// Move the game to its terminal phase for a resolved move-channel outcome.
+    /// Provisional only: `settle_move_channel` and `dispute_move_channel` both call
+    /// this, and a later strictly-higher-nonce dispute is expected to call it again
+    /// and overwrite the outcome before the dispute window closes. Stats and the
+    /// hub notification are deliberately *not* settled here — see
+    /// `claim_move_channel_settlement`, which runs them exactly once, after the
+    /// window closes, off whatever outcome is recorded by then.
+    fn record_move_channel_outcome(
+        env: &Env,
+        game_id: &Address,
+        winner: &Option<Address>,
+    ) {
+        let phase_key = Self::key_game_phase(game_id);
+        match winner {
+            Some(w) => {
+                env.storage().temporary().set(&phase_key, &PHASE_FINALIZED);
+                let win_key = Self::key_game_winner(game_id);
+                env.storage().temporary().set(&win_key, w);
+                env.storage().temporary().extend_ttl(&win_key, 5000, 5000);
+            }
+            None => {
+                env.storage().temporary().set(&phase_key, &PHASE_DRAW);
+            }
+        }
+    }
+
+    /// Settle stats and notify the game hub for a resolved move-channel game, once
+    /// the dispute window has passed undisputed. Mirrors the side-bet channel's
+    /// close/dispute-then-`claim_channel_payout` split: `settle_move_channel` and
+    /// `dispute_move_channel` only ever record a *provisional* outcome (so a later
+    /// dispute can still override it), and stats must only be counted once the
+    /// outcome is final — otherwise a settle followed by an overriding dispute would
+    /// double-count the game, or credit the original (pre-dispute) winner first.
+    pub fn claim_move_channel_settlement(env: Env, game_id: Address) -> Result<(), Error> {
+        let dead_key = Self::key_move_channel_dispute_until(&game_id);
+        let dispute_until: u64 = env.storage().temporary().get(&dead_key).ok_or(Error::MoveChannelNotOpened)?;
+        if env.ledger().timestamp() <= dispute_until {
+            return Err(Error::MoveChannelDisputeWindowClosed);
+        }
+
+        let settled_key = Self::key_move_channel_settled(&game_id);
+        let already_settled: bool = env.storage().temporary().get(&settled_key).unwrap_or(false);
+        if already_settled {
+            return Err(Error::AlreadyWithdrawn);
+        }
+
+        let player1: Address = env.storage().temporary().get(&Self::key_game_p1(&game_id)).ok_or(Error::NoActiveGame)?;
+        let player2: Address = env.storage().temporary().get(&Self::key_game_p2(&game_id)).ok_or(Error::NoActiveGame)?;
+
+        let phase_key = Self::key_game_phase(&game_id);
+        let phase: u32 = env.storage().temporary().get(&phase_key).ok_or(Error::NoActiveGame)?;
+        if phase != PHASE_FINALIZED && phase != PHASE_DRAW {
+            return Err(Error::WrongPhase);
+        }
+        let winner: Option<Address> = if phase == PHASE_FINALIZED {
+            Some(env.storage().temporary().get(&Self::key_game_winner(&game_id)).ok_or(Error::NoActiveGame)?)
+        } else {
+            None
+        };
+
+        let player1_won = winner.as_ref() == Some(&player1);
+        Self::call_end_game(&env, &game_id, player1_won);
+        Self::settle_player_stats(&env, &game_id, &player1, &player2, &winner);
+
+        env.storage().temporary().set(&settled_key, &true);
+        env.storage().temporary().extend_ttl(&settled_key, 5000, 5000);
+
+        Ok(())
+    }
+
+    /// Open an off-chain move channel for an already-active game: from here, players
+    /// exchange signed `MoveChannelState` tuples off-chain via their channel keys and
+    /// only touch the contract again to settle or dispute, skipping the per-move
+    /// on-chain ZK verification `submit_turn` would otherwise require for every turn.
+    pub fn open_move_channel(
+        env: Env,
+        game_id: Address,
+        p1: Address,
+        p2: Address,
+        escrow: i128,
+    ) -> Result<(), Error> {
+        let phase_key = Self::key_game_phase(&game_id);
+        let phase: u32 = env.storage().temporary().get(&phase_key).ok_or(Error::NoActiveGame)?;
+        if phase != PHASE_ACTIVE {
+            return Err(Error::WrongPhase);
+        }
+
+        let player1: Address = env.storage().temporary().get(&Self::key_game_p1(&game_id)).ok_or(Error::NoActiveGame)?;
+        let player2: Address = env.storage().temporary().get(&Self::key_game_p2(&game_id)).ok_or(Error::NoActiveGame)?;
+        if p1 != player1 || p2 != player2 {
+            return Err(Error::WrongPlayer);
+        }
+
+        let escrow_per_player: i128 = env.storage().temporary().get(&Self::key_escrow_amount(&game_id)).unwrap_or(0);
+        if escrow != escrow_per_player * 2 {
+            return Err(Error::ChannelBalanceMismatch);
+        }
+
+        let nonce_key = Self::key_move_channel_nonce(&game_id);
+        env.storage().temporary().set(&nonce_key, &0u64);
+        env.storage().temporary().extend_ttl(&nonce_key, 5000, 5000);
+        Ok(())
+    }
+
+    /// Settle the game from the highest-nonce state both players co-signed off-chain,
+    /// skipping per-move proof verification entirely. Finalizes the winner
+    /// immediately, but the outcome can still be overridden by `dispute_move_channel`
+    /// posting a strictly higher nonce before the dispute window closes.
+    pub fn settle_move_channel(
+        env: Env,
+        game_id: Address,
+        final_state: MoveChannelState,
+        sig_p1: BytesN<64>,
+        sig_p2: BytesN<64>,
+    ) -> Result<(), Error> {
+        let player1: Address = env.storage().temporary().get(&Self::key_game_p1(&game_id)).ok_or(Error::NoActiveGame)?;
+        let player2: Address = env.storage().temporary().get(&Self::key_game_p2(&game_id)).ok_or(Error::NoActiveGame)?;
+
+        let nonce_key = Self::key_move_channel_nonce(&game_id);
+        let current_nonce: u64 = env.storage().temporary().get(&nonce_key).ok_or(Error::MoveChannelNotOpened)?;
+        if final_state.nonce <= current_nonce {
+            return Err(Error::MoveChannelStaleState);
+        }
+
+        let pk1: BytesN<65> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_pubkey(&game_id, &player1))
+            .ok_or(Error::ChannelPubkeyNotSet)?;
+        let pk2: BytesN<65> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_pubkey(&game_id, &player2))
+            .ok_or(Error::ChannelPubkeyNotSet)?;
+
+        let digest = Self::move_channel_state_digest(&env, &game_id, &final_state);
+        env.crypto().secp256r1_verify(&pk1, &digest, &sig_p1);
+        env.crypto().secp256r1_verify(&pk2, &digest, &sig_p2);
+
+        let winner = Self::move_channel_winner(&final_state, &player1, &player2)?;
+
+        env.storage().temporary().set(&nonce_key, &final_state.nonce);
+        env.storage().temporary().extend_ttl(&nonce_key, 5000, 5000);
+
+        let dispute_until = env.ledger().timestamp() + CHANNEL_DISPUTE_SECS;
+        let dead_key = Self::key_move_channel_dispute_until(&game_id);
+        env.storage().temporary().set(&dead_key, &dispute_until);
+        env.storage().temporary().extend_ttl(&dead_key, 5000, 5000);
+
+        Self::record_move_channel_outcome(&env, &game_id, &winner);
+        Ok(())
+    }
+
+    /// Override a settled move-channel outcome with a strictly higher-nonce state
+    /// during the dispute window. Unlike `settle_move_channel`, which trusts both
+    /// signatures, a dispute only carries the claimant's own signature — so the
+    /// claimed guess and its result must additionally be authenticated by the same
+    /// Merkle-membership and ZK proof checks `submit_turn` runs, so a dispute can't
+    /// just relitigate the outcome on say-so.
+    pub fn dispute_move_channel(
+        env: Env,
+        game_id: Address,
+        caller: Address,
+        claimed_state: MoveChannelState,
+        sig: BytesN<64>,
+        path_elements: Vec<BytesN<32>>,
+        path_indices: Vec<u32>,
+        rln_share_x: BytesN<32>,
+        rln_share_y: BytesN<32>,
+        rln_nullifier: BytesN<32>,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+    ) -> Result<(), Error> {
+        let actual_caller = Self::resolve_caller_simple(&env, &game_id, &caller);
+
+        let dead_key = Self::key_move_channel_dispute_until(&game_id);
+        let dispute_until: u64 = env.storage().temporary().get(&dead_key).ok_or(Error::MoveChannelNotOpened)?;
+        if env.ledger().timestamp() > dispute_until {
+            return Err(Error::MoveChannelDisputeWindowClosed);
+        }
+
+        let nonce_key = Self::key_move_channel_nonce(&game_id);
+        let current_nonce: u64 = env.storage().temporary().get(&nonce_key).unwrap_or(0);
+        if claimed_state.nonce <= current_nonce {
+            return Err(Error::MoveChannelStaleState);
+        }
+
+        let pk: BytesN<65> = env
+            .storage()
+            .temporary()
+            .get(&Self::key_channel_pubkey(&game_id, &actual_caller))
+            .ok_or(Error::ChannelPubkeyNotSet)?;
+        let digest = Self::move_channel_state_digest(&env, &game_id, &claimed_state);
+        env.crypto().secp256r1_verify(&pk, &digest, &sig);
+
+        // The claimant's own word must be a real dictionary entry...
+        Self::do_verify_guess(
+            &env,
+            &claimed_state.guess_word,
+            &path_elements,
+            &path_indices,
+            &rln_share_x,
+            &rln_share_y,
+            &rln_nullifier,
+        )?;
+
+        let player1: Address = env.storage().temporary().get(&Self::key_game_p1(&game_id)).ok_or(Error::NoActiveGame)?;
+        let player2: Address = env.storage().temporary().get(&Self::key_game_p2(&game_id)).ok_or(Error::NoActiveGame)?;
+
+        // ...and the claimed results must be backed by a real ZK proof against the
+        // *opponent's* commitment, exactly as `submit_turn` requires on-chain.
+        let guesser = if claimed_state.turn % 2 == 1 { &player1 } else { &player2 };
+        let opponent_commitment: BytesN<32> = if guesser == &player1 {
+            env.storage().temporary().get(&Self::key_game_c2(&game_id)).ok_or(Error::NoActiveGame)?
+        } else {
+            env.storage().temporary().get(&Self::key_game_c1(&game_id)).ok_or(Error::NoActiveGame)?
+        };
+        let commitment_from_pi = Self::extract_commitment_from_pi(&env, &public_inputs);
+        if opponent_commitment != commitment_from_pi {
+            return Err(Error::GuessWordMismatch);
+        }
+        if !Self::pi_letters_match(&public_inputs, &claimed_state.guess_word) {
+            return Err(Error::GuessWordMismatch);
+        }
+        if claimed_state.results.len() != 5 {
+            return Err(Error::GuessWordMismatch);
+        }
+        for i in 0u32..5 {
+            let offset = 192 + i * 32 + 31;
+            if public_inputs.get(offset).unwrap_or(0) != claimed_state.results.get(i).unwrap_or(0) {
+                return Err(Error::GuessWordMismatch);
+            }
+        }
+        Self::do_verify_proof(&env, &public_inputs, &proof_bytes)?;
+
+        let winner = Self::move_channel_winner(&claimed_state, &player1, &player2)?;
+
+        env.storage().temporary().set(&nonce_key, &claimed_state.nonce);
+        env.storage().temporary().extend_ttl(&nonce_key, 5000, 5000);
+
+        Self::record_move_channel_outcome(&env, &game_id, &winner);
+        Ok(())
+    }
+
+    // ── Query functions ──────────────────────────────────────────────────
+
+    pub fn get_game_phase(env: Env, game_id: Address) -> u32 {
+        let key = Self::key_game_phase(&game_id);
+        env.storage().temporary().get(&key).unwrap_or(255)
+    }
+
+    pub fn get_game_turn(env: Env, game_id: Address) -> u32 {
+        let key = Self::key_game_turn(&game_id);
+        env.storage().temporary().get(&key).unwrap_or(0)
+    }
+
+    pub fn get_game_deadline(env: Env, game_id: Address) -> u64 {
+        let key = Self::key_game_deadline(&game_id);
+        env.storage().temporary().get(&key).unwrap_or(0)
+    }
+
+    pub fn get_last_guess(env: Env, game_id: Address) -> Bytes {
+        let key = Self::key_game_guess(&game_id);
+        env.storage().temporary().get(&key).unwrap_or(Bytes::new(&env))
+    }
+
+    pub fn get_last_results(env: Env, game_id: Address) -> Bytes {
+        let key = Self::key_game_results(&game_id);
+        env.storage().temporary().get(&key).unwrap_or(Bytes::new(&env))
+    }
+
+    pub fn get_player1(env: Env, game_id: Address) -> Address {
         let key = Self::key_game_p1(&game_id);
         env.storage().temporary().get(&key).unwrap_or(game_id)
     }
@@ -1235,14 +2285,248 @@ impl TwoPlayerWordleContract {
         env.storage().persistent().get(&key).unwrap_or(game_id)
     }
 
-    /// Standalone Merkle proof check (Poseidon2).
+    // ── Player rating queries (persistent storage) ───────────────────────
+
+    pub fn get_player_rating(env: Env, player: Address) -> i32 {
+        Self::player_rating(&env, &player)
+    }
+
+    pub fn get_player_record(env: Env, player: Address) -> PlayerRecord {
+        env.storage()
+            .persistent()
+            .get(&Self::key_player_record(&player))
+            .unwrap_or(PlayerRecord {
+                wins: 0,
+                losses: 0,
+                draws: 0,
+                rating: DEFAULT_RATING,
+            })
+    }
+
+    pub fn get_recent_results(env: Env, player: Address) -> Vec<GameResult> {
+        env.storage()
+            .persistent()
+            .get(&Self::key_player_results(&player))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Admin-only: append a word to the on-chain dictionary Merkle tree, updating
+    /// the root the same way the off-chain `filled_subtrees` cache would. Words
+    /// are appended in order — there is no removal or rotation, only growth.
+    pub fn insert_word(env: Env, caller: Address, word: Bytes) -> Result<(), Error> {
+        caller.require_auth();
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Self::key_admin())
+            .ok_or(Error::NotAdmin)?;
+        if caller != admin {
+            return Err(Error::NotAdmin);
+        }
+
+        let leaf_value = Self::word_leaf_value(&env, &word)?;
+        let mut next_index: u32 = env
+            .storage()
+            .instance()
+            .get(&Self::key_merkle_next_idx())
+            .unwrap_or(0);
+        if next_index >= (1u32 << MERKLE_TREE_DEPTH) {
+            return Err(Error::MerkleTreeFull);
+        }
+
+        let zero_hashes: Vec<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&Self::key_merkle_zero_hashes())
+            .unwrap();
+        let mut filled_subtrees: Vec<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&Self::key_merkle_filled_subtrees())
+            .unwrap();
+
+        let field = Symbol::new(&env, "BN254");
+        let mut current_hash = leaf_value;
+        let mut index = next_index;
+
+        for level in 0..MERKLE_TREE_DEPTH {
+            let mut inputs = Vec::new(&env);
+            if index % 2 == 0 {
+                filled_subtrees.set(level, Self::u256_to_bytesn(&env, &current_hash));
+                let zero_sibling = U256::from_be_bytes(&env, &zero_hashes.get(level).unwrap().into());
+                inputs.push_back(current_hash.clone());
+                inputs.push_back(zero_sibling);
+            } else {
+                let left = U256::from_be_bytes(&env, &filled_subtrees.get(level).unwrap().into());
+                inputs.push_back(left);
+                inputs.push_back(current_hash.clone());
+            }
+            current_hash = env.crypto().poseidon2_hash(&inputs, field.clone());
+            index /= 2;
+        }
+
+        next_index += 1;
+        env.storage().instance().set(&Self::key_merkle_filled_subtrees(), &filled_subtrees);
+        env.storage().instance().set(&Self::key_merkle_next_idx(), &next_index);
+        env.storage().instance().set(&Self::key_merkle_root(), &Self::u256_to_bytesn(&env, &current_hash));
+
+        Ok(())
+    }
+
+    /// Standalone Merkle proof check (Poseidon2) plus RLN rate-limit bookkeeping.
     pub fn verify_guess(
         env: Env,
         guess_word: Bytes,
         path_elements: Vec<BytesN<32>>,
         path_indices: Vec<u32>,
+        share_x: BytesN<32>,
+        share_y: BytesN<32>,
+        nullifier: BytesN<32>,
     ) -> Result<(), Error> {
-        Self::do_verify_guess(&env, &guess_word, &path_elements, &path_indices)
+        Self::do_verify_guess(
+            &env,
+            &guess_word,
+            &path_elements,
+            &path_indices,
+            &share_x,
+            &share_y,
+            &nullifier,
+        )
+    }
+
+    /// Authenticate several guesses against the dictionary root in one pass using a
+    /// pruned Merkle tree (rust-bitcoin `PartialMerkleTree` style): `bits` is a
+    /// depth-first preorder walk of the full depth-`MERKLE_TREE_DEPTH` tree — `true`
+    /// descends into a subtree, `false` prunes it and consumes the next entry of
+    /// `hashes` as that subtree's root instead. At the leaf level a `true` bit
+    /// consumes the next entry of `guess_words` (one of the leaves we want to
+    /// authenticate) rather than a hash. Internal nodes combine with
+    /// `poseidon2_hash` exactly as `do_verify_guess` does for a single path;
+    /// shared ancestors are hashed once instead of once per guess. Unlike Bitcoin's
+    /// transaction tree, this dictionary tree is always a perfect binary tree (empty
+    /// slots are zero-hash-padded out to `2^MERKLE_TREE_DEPTH`), so there is no
+    /// odd-leaf-duplication rule to apply. Returns one bool per `guess_words` entry;
+    /// since a single root underwrites every leaf, the whole batch is all-or-nothing
+    /// by construction — all `true` or the call fails before returning.
+    pub fn verify_guesses_batch(
+        env: Env,
+        guess_words: Vec<Bytes>,
+        bits: Vec<bool>,
+        hashes: Vec<BytesN<32>>,
+    ) -> Result<Vec<bool>, Error> {
+        let mut leaves = Vec::new(&env);
+        for i in 0..guess_words.len() {
+            leaves.push_back(Self::word_leaf_value(&env, &guess_words.get(i).unwrap())?);
+        }
+
+        let field = Symbol::new(&env, "BN254");
+        let mut bit_idx = 0u32;
+        let mut hash_idx = 0u32;
+        let mut leaf_idx = 0u32;
+        let root = Self::traverse_partial_tree(
+            &env,
+            MERKLE_TREE_DEPTH,
+            &field,
+            &bits,
+            &hashes,
+            &leaves,
+            &mut bit_idx,
+            &mut hash_idx,
+            &mut leaf_idx,
+        )?;
+
+        if bit_idx != bits.len() || hash_idx != hashes.len() || leaf_idx != leaves.len() {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let stored_root_bytesn: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&Self::key_merkle_root())
+            .ok_or(Error::MerkleRootNotSet)?;
+        let stored_root = U256::from_be_bytes(&env, &stored_root_bytesn.into());
+        if root != stored_root {
+            return Err(Error::InvalidMerkleProof);
+        }
+
+        let mut results = Vec::new(&env);
+        for _ in 0..guess_words.len() {
+            results.push_back(true);
+        }
+        Ok(results)
+    }
+
+    /// Recursive step of `verify_guesses_batch`'s depth-first traversal. `height`
+    /// counts down from `MERKLE_TREE_DEPTH` to `0` (a leaf). The three cursors track
+    /// how far each of the three parallel streams (`bits`, `hashes`, `leaves`) has
+    /// been consumed so far.
+    fn traverse_partial_tree(
+        env: &Env,
+        height: u32,
+        field: &Symbol,
+        bits: &Vec<bool>,
+        hashes: &Vec<BytesN<32>>,
+        leaves: &Vec<U256>,
+        bit_idx: &mut u32,
+        hash_idx: &mut u32,
+        leaf_idx: &mut u32,
+    ) -> Result<U256, Error> {
+        let descend = bits.get(*bit_idx).ok_or(Error::InvalidMerkleProof)?;
+        *bit_idx += 1;
+
+        if height == 0 {
+            if descend {
+                let leaf = leaves.get(*leaf_idx).ok_or(Error::InvalidMerkleProof)?;
+                *leaf_idx += 1;
+                return Ok(leaf);
+            }
+            let hash = hashes.get(*hash_idx).ok_or(Error::InvalidMerkleProof)?;
+            *hash_idx += 1;
+            return Ok(U256::from_be_bytes(env, &hash.into()));
+        }
+
+        if !descend {
+            let hash = hashes.get(*hash_idx).ok_or(Error::InvalidMerkleProof)?;
+            *hash_idx += 1;
+            return Ok(U256::from_be_bytes(env, &hash.into()));
+        }
+
+        let left = Self::traverse_partial_tree(
+            env, height - 1, field, bits, hashes, leaves, bit_idx, hash_idx, leaf_idx,
+        )?;
+        let right = Self::traverse_partial_tree(
+            env, height - 1, field, bits, hashes, leaves, bit_idx, hash_idx, leaf_idx,
+        )?;
+        let mut inputs = Vec::new(env);
+        inputs.push_back(left);
+        inputs.push_back(right);
+        Ok(env.crypto().poseidon2_hash(&inputs, field.clone()))
+    }
+
+    /// Recover a leaked RLN identity secret `a0` for `nullifier`. Idempotent: if
+    /// `record_rln_share` already recovered it on the `RLN_MAX_GUESSES + 1`-th share,
+    /// returns that value; otherwise recomputes on demand provided enough shares
+    /// have been submitted, and persists the result either way.
+    pub fn recover_secret(env: Env, nullifier: BytesN<32>) -> Result<BytesN<32>, Error> {
+        let secret_key = Self::key_rln_secret(&nullifier);
+        if let Some(secret) = env.storage().persistent().get(&secret_key) {
+            return Ok(secret);
+        }
+
+        let shares_key = Self::key_rln_shares(&nullifier);
+        let shares: Vec<RlnShare> = env
+            .storage()
+            .persistent()
+            .get(&shares_key)
+            .unwrap_or(Vec::new(&env));
+        if shares.len() <= RLN_MAX_GUESSES {
+            return Err(Error::RlnInsufficientShares);
+        }
+
+        let secret = Self::recover_rln_secret(&env, &shares);
+        env.storage().persistent().set(&secret_key, &secret);
+        env.storage().persistent().extend_ttl(&secret_key, 20_000, 20_000);
+        Ok(secret)
     }
 
     // ── Private helpers ──────────────────────────────────────────────────
@@ -1268,6 +2552,17 @@ impl TwoPlayerWordleContract {
         true
     }
 
+    /// Extract the semaphore-style `nullifier = Poseidon2(secret, round_id)` the
+    /// guess-result circuit emits as its 12th public input (field 11, offset 352).
+    fn extract_nullifier_from_pi(env: &Env, public_inputs: &Bytes) -> BytesN<32> {
+        let mut buf = [0u8; 32];
+        let base = 11 * 32;
+        for i in 0..32u32 {
+            buf[i as usize] = public_inputs.get(base + i).unwrap_or(0);
+        }
+        BytesN::from_array(env, &buf)
+    }
+
     /// Common reveal verification: commitment + letters + all-correct results + ZK proof.
     fn do_verify_reveal(
         env: &Env,
@@ -1354,34 +2649,18 @@ impl TwoPlayerWordleContract {
         Ok(())
     }
 
+    /// `share_x`/`share_y` are this guess's RLN polynomial share and `nullifier` is
+    /// `Poseidon2(a1, round_id)`; see `record_rln_share` for the rate-limit check.
     fn do_verify_guess(
         env: &Env,
         guess_word: &Bytes,
         path_elements: &Vec<BytesN<32>>,
         path_indices: &Vec<u32>,
+        share_x: &BytesN<32>,
+        share_y: &BytesN<32>,
+        nullifier: &BytesN<32>,
     ) -> Result<(), Error> {
-        if guess_word.len() != 5 {
-            return Err(Error::InvalidGuessLength);
-        }
-
-        let mut word_bytes = [0u8; 5];
-        for i in 0..5 {
-            let b = guess_word.get(i as u32).unwrap();
-            if b < 0x61 || b > 0x7A {
-                return Err(Error::InvalidCharacter);
-            }
-            word_bytes[i] = b;
-        }
-
-        // Compute leaf as a field element: l1*256^4 + l2*256^3 + l3*256^2 + l4*256 + l5
-        // This matches the Noir circuit and JS Poseidon Merkle tree leaf encoding
-        let leaf_value: u128 = (word_bytes[0] as u128) * 256u128.pow(4)
-            + (word_bytes[1] as u128) * 256u128.pow(3)
-            + (word_bytes[2] as u128) * 256u128.pow(2)
-            + (word_bytes[3] as u128) * 256
-            + (word_bytes[4] as u128);
-
-        let mut current_hash = U256::from_u128(env, leaf_value);
+        let mut current_hash = Self::word_leaf_value(env, guess_word)?;
 
         let field = Symbol::new(env, "BN254");
         let depth = path_elements.len();
@@ -1403,12 +2682,237 @@ impl TwoPlayerWordleContract {
             current_hash = env.crypto().poseidon2_hash(&inputs, field.clone());
         }
 
-        let stored_root_bytes: Bytes = BytesN::from_array(env, &MERKLE_ROOT).into();
-        let stored_root = U256::from_be_bytes(env, &stored_root_bytes);
+        let stored_root_bytes: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&Self::key_merkle_root())
+            .ok_or(Error::MerkleRootNotSet)?;
+        let stored_root = U256::from_be_bytes(env, &stored_root_bytes.into());
         if current_hash != stored_root {
             return Err(Error::InvalidMerkleProof);
         }
 
+        Self::record_rln_share(env, nullifier, share_x, share_y)?;
+
+        Ok(())
+    }
+
+    /// Poseidon2 sponge (Dusk Poseidon252-style) absorbing a word of any length into
+    /// one `U256` leaf. Bytes are absorbed `RATE` at a time, each block XOR-free —
+    /// just fed alongside the running state into `poseidon2_hash` as the permutation
+    /// — padded per the standard rule (a single `0x01` byte then zeros) so a message
+    /// that is an exact multiple of `RATE` still gets a distinguishing final block.
+    /// Kept for both `do_verify_guess` and `insert_word`, which must agree on leaf
+    /// encoding for the Merkle proof to verify.
+    fn word_leaf_value(env: &Env, word: &Bytes) -> Result<U256, Error> {
+        let len = word.len();
+        if len == 0 {
+            return Err(Error::InvalidGuessLength);
+        }
+        for i in 0..len {
+            let b = word.get(i).unwrap();
+            if b < 0x61 || b > 0x7A {
+                return Err(Error::InvalidCharacter);
+            }
+        }
+
+        const RATE: u32 = 31;
+        let field = Symbol::new(env, "BN254");
+        let mut state = U256::from_u128(env, 0);
+        let mut offset: u32 = 0;
+
+        while offset < len {
+            let take = core::cmp::min(RATE, len - offset);
+            let mut chunk = [0u8; 32];
+            for i in 0..take {
+                chunk[1 + i as usize] = word.get(offset + i).unwrap();
+            }
+            offset += take;
+            if take < RATE {
+                chunk[1 + take as usize] = 0x01;
+            }
+            state = Self::poseidon2_absorb(env, &field, &state, &chunk);
+
+            if take == RATE && offset == len {
+                let mut pad = [0u8; 32];
+                pad[1] = 0x01;
+                state = Self::poseidon2_absorb(env, &field, &state, &pad);
+            }
+        }
+
+        Ok(state)
+    }
+
+    /// One sponge permutation step: hash the running `state` together with a
+    /// big-endian 32-byte absorbed block and return the new state.
+    fn poseidon2_absorb(env: &Env, field: &Symbol, state: &U256, chunk_be: &[u8; 32]) -> U256 {
+        let chunk = U256::from_be_bytes(env, &Bytes::from_array(env, chunk_be));
+        let mut inputs = Vec::new(env);
+        inputs.push_back(state.clone());
+        inputs.push_back(chunk);
+        env.crypto().poseidon2_hash(&inputs, field.clone())
+    }
+
+    /// Record one RLN polynomial share under `nullifier`, rejecting a replayed
+    /// `(nullifier, x)` pair, and — the instant the count first reaches
+    /// `RLN_MAX_GUESSES + 1` — recover and persist the leaked identity secret `a0`
+    /// by Lagrange-interpolating the now fully determined polynomial at x = 0.
+    fn record_rln_share(
+        env: &Env,
+        nullifier: &BytesN<32>,
+        share_x: &BytesN<32>,
+        share_y: &BytesN<32>,
+    ) -> Result<(), Error> {
+        let shares_key = Self::key_rln_shares(nullifier);
+        let mut shares: Vec<RlnShare> = env
+            .storage()
+            .persistent()
+            .get(&shares_key)
+            .unwrap_or(Vec::new(env));
+
+        for i in 0..shares.len() {
+            if &shares.get(i).unwrap().x == share_x {
+                return Err(Error::RlnDuplicateShare);
+            }
+        }
+
+        shares.push_back(RlnShare {
+            x: share_x.clone(),
+            y: share_y.clone(),
+        });
+        env.storage().persistent().set(&shares_key, &shares);
+        env.storage().persistent().extend_ttl(&shares_key, 20_000, 20_000);
+
+        if shares.len() == RLN_MAX_GUESSES + 1 {
+            let secret = Self::recover_rln_secret(env, &shares);
+            let secret_key = Self::key_rln_secret(nullifier);
+            env.storage().persistent().set(&secret_key, &secret);
+            env.storage().persistent().extend_ttl(&secret_key, 20_000, 20_000);
+        }
+
         Ok(())
     }
+
+    /// Lagrange-interpolate the degree-`RLN_MAX_GUESSES` polynomial `A` at x = 0
+    /// from its shares, recovering the secret constant term `a0 = A(0)`:
+    /// `a0 = Σ y_i · Π_{j≠i} x_j / (x_j - x_i)`, all arithmetic mod the BN254
+    /// scalar field.
+    fn recover_rln_secret(env: &Env, shares: &Vec<RlnShare>) -> BytesN<32> {
+        let m = Self::bn254_modulus(env);
+        let n = shares.len();
+
+        let mut secret = U256::from_u128(env, 0);
+        for i in 0..n {
+            let share_i = shares.get(i).unwrap();
+            let xi_bytes: Bytes = share_i.x.into();
+            let xi = U256::from_be_bytes(env, &xi_bytes);
+            let yi_bytes: Bytes = share_i.y.into();
+            let yi = U256::from_be_bytes(env, &yi_bytes);
+
+            let mut num = U256::from_u128(env, 1);
+            let mut den = U256::from_u128(env, 1);
+            for j in 0..n {
+                if j == i {
+                    continue;
+                }
+                let xj_bytes: Bytes = shares.get(j).unwrap().x.into();
+                let xj = U256::from_be_bytes(env, &xj_bytes);
+
+                num = Self::mod_mul(env, &num, &xj, &m);
+                let diff = Self::mod_sub(&xj, &xi, &m);
+                den = Self::mod_mul(env, &den, &diff, &m);
+            }
+
+            let den_inv = Self::mod_inv(env, &den, &m);
+            let coeff = Self::mod_mul(env, &num, &den_inv, &m);
+            let term = Self::mod_mul(env, &yi, &coeff, &m);
+            secret = Self::mod_add(&secret, &term, &m);
+        }
+
+        let secret_bytes = secret.to_be_bytes();
+        let mut buf = [0u8; 32];
+        for i in 0..32u32 {
+            buf[i as usize] = secret_bytes.get(i).unwrap_or(0);
+        }
+        BytesN::from_array(env, &buf)
+    }
+
+    fn bn254_modulus(env: &Env) -> U256 {
+        let bytes: Bytes = BytesN::from_array(env, &BN254_SCALAR_MODULUS).into();
+        U256::from_be_bytes(env, &bytes)
+    }
+
+    fn u256_be_bytes(u: &U256) -> [u8; 32] {
+        let bytes = u.to_be_bytes();
+        let mut out = [0u8; 32];
+        for i in 0..32u32 {
+            out[i as usize] = bytes.get(i).unwrap_or(0);
+        }
+        out
+    }
+
+    fn u256_to_bytesn(env: &Env, u: &U256) -> BytesN<32> {
+        BytesN::from_array(env, &Self::u256_be_bytes(u))
+    }
+
+    fn mod_add(a: &U256, b: &U256, m: &U256) -> U256 {
+        let sum = a.clone() + b.clone();
+        if sum >= *m {
+            sum - m.clone()
+        } else {
+            sum
+        }
+    }
+
+    fn mod_sub(a: &U256, b: &U256, m: &U256) -> U256 {
+        if a >= b {
+            a.clone() - b.clone()
+        } else {
+            m.clone() + a.clone() - b.clone()
+        }
+    }
+
+    /// Modular multiplication via double-and-add over `b`'s bits, avoiding the need
+    /// for a wider-than-256-bit intermediate product. Assumes `a` and `b` are
+    /// already field elements (< `m`), which holds for circuit-emitted values.
+    fn mod_mul(env: &Env, a: &U256, b: &U256, m: &U256) -> U256 {
+        let bbytes = Self::u256_be_bytes(b);
+        let mut result = U256::from_u128(env, 0);
+        let mut addend = a.clone();
+        for byte_idx in (0..32usize).rev() {
+            let byte = bbytes[byte_idx];
+            for bit in 0..8u32 {
+                if (byte >> bit) & 1 == 1 {
+                    result = Self::mod_add(&result, &addend, m);
+                }
+                addend = Self::mod_add(&addend, &addend, m);
+            }
+        }
+        result
+    }
+
+    /// Modular exponentiation via square-and-multiply over `exp`'s bits.
+    fn mod_pow(env: &Env, base: &U256, exp: &U256, m: &U256) -> U256 {
+        let ebytes = Self::u256_be_bytes(exp);
+        let mut result = U256::from_u128(env, 1);
+        let mut b = base.clone();
+        for byte_idx in (0..32usize).rev() {
+            let byte = ebytes[byte_idx];
+            for bit in 0..8u32 {
+                if (byte >> bit) & 1 == 1 {
+                    result = Self::mod_mul(env, &result, &b, m);
+                }
+                b = Self::mod_mul(env, &b, &b, m);
+            }
+        }
+        result
+    }
+
+    /// Modular inverse via Fermat's little theorem: `a^(m-2) mod m`, valid since the
+    /// BN254 scalar field modulus is prime.
+    fn mod_inv(env: &Env, a: &U256, m: &U256) -> U256 {
+        let two = U256::from_u128(env, 2);
+        let exp = m.clone() - two;
+        Self::mod_pow(env, a, &exp, m)
+    }
 }
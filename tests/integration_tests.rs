@@ -1,4 +1,5 @@
-use soroban_sdk::{Bytes, Env};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol, U256};
 use ultrahonk_soroban_verifier::PROOF_BYTES;
 
 const CONTRACT_WASM: &[u8] =
@@ -10,25 +11,356 @@ mod ultrahonk_contract {
     );
 }
 
-fn register_client<'a>(env: &'a Env, vk_bytes: &Bytes) -> ultrahonk_contract::Client<'a> {
-    let contract_id = env.register(CONTRACT_WASM, (vk_bytes.clone(),));
+// There is no standalone `verify_proof` entrypoint on the game contract to
+// black-box test the way the word-commit contract's is tested below — proof
+// verification here only happens inline inside entrypoints such as
+// `submit_turn`/`reveal_word`/`redeem_payout` via the private `do_verify_proof`. `register_game_client` further down
+// exercises this wasm's real constructor and entrypoints instead.
+
+const WORD_COMMIT_WASM: &[u8] =
+    include_bytes!("../target/wasm32v1-none/release/word_commit_verifier.wasm");
+
+mod word_commit_contract {
+    soroban_sdk::contractimport!(
+        file = "target/wasm32v1-none/release/word_commit_verifier.wasm"
+    );
+}
+
+fn wc_fixture(env: &Env) -> (Bytes, Bytes, Bytes, BytesN<32>) {
+    let vk_bytes_raw: &[u8] = include_bytes!("../circuit/target/wc_vk");
+    let proof_bin: &[u8] = include_bytes!("../circuit/target/wc_proof");
+    let pub_inputs_bin: &[u8] = include_bytes!("../circuit/target/wc_public_inputs");
+    assert_eq!(proof_bin.len(), PROOF_BYTES);
+
+    let vk_bytes = Bytes::from_slice(env, vk_bytes_raw);
+    let proof_bytes = Bytes::from_slice(env, proof_bin);
+    let public_inputs = Bytes::from_slice(env, pub_inputs_bin);
+    // Field 0 of the fixture's public inputs is the Merkle root the circuit proved against.
+    let mut root_bytes = [0u8; 32];
+    root_bytes.copy_from_slice(&pub_inputs_bin[0..32]);
+    let root = BytesN::from_array(env, &root_bytes);
+
+    (vk_bytes, proof_bytes, public_inputs, root)
+}
+
+fn register_word_commit_client<'a>(
+    env: &'a Env,
+    vk_bytes: &Bytes,
+    external_nullifier: &BytesN<32>,
+    root: &BytesN<32>,
+    admin: &Address,
+) -> word_commit_contract::Client<'a> {
+    let contract_id = env.register(
+        WORD_COMMIT_WASM,
+        (vk_bytes.clone(), external_nullifier.clone(), root.clone(), admin.clone()),
+    );
+    word_commit_contract::Client::new(env, &contract_id)
+}
+
+#[test]
+fn verify_proof_rejects_replayed_nullifier() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, root) = wc_fixture(&env);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+
+    let client = register_word_commit_client(&env, &vk_bytes, &external_nullifier, &root, &admin);
+
+    let default_circuit: Symbol = symbol_short!("deflt5");
+    client.verify_proof(&default_circuit, &public_inputs, &proof_bytes);
+
+    let result = client.try_verify_proof(&default_circuit, &public_inputs, &proof_bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn verify_proof_rejects_root_mismatch() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, _root) = wc_fixture(&env);
+    let wrong_root = BytesN::from_array(&env, &[0xAAu8; 32]);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+
+    let client =
+        register_word_commit_client(&env, &vk_bytes, &external_nullifier, &wrong_root, &admin);
+
+    let default_circuit: Symbol = symbol_short!("deflt5");
+    let result = client.try_verify_proof(&default_circuit, &public_inputs, &proof_bytes);
+    assert!(result.is_err());
+}
+
+#[test]
+fn update_root_rotates_root_so_matching_proof_verifies() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, root) = wc_fixture(&env);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+    let wrong_root = BytesN::from_array(&env, &[0xAAu8; 32]);
+
+    let client = register_word_commit_client(&env, &vk_bytes, &external_nullifier, &wrong_root, &admin);
+    client.update_root(&root);
+
+    let default_circuit: Symbol = symbol_short!("deflt5");
+    client.verify_proof(&default_circuit, &public_inputs, &proof_bytes);
+}
+
+#[test]
+fn register_vk_adds_a_second_circuit_without_touching_the_default_one() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, root) = wc_fixture(&env);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+
+    let client = register_word_commit_client(&env, &vk_bytes, &external_nullifier, &root, &admin);
+
+    let six_letter_circuit: Symbol = symbol_short!("six6");
+    client.register_vk(&six_letter_circuit, &vk_bytes);
+    client.verify_proof(&six_letter_circuit, &public_inputs, &proof_bytes);
+
+    // Nullifiers are not scoped by circuit id, so the same proof is now a replay;
+    // reaching the nullifier check still shows the default VK loaded and verified it.
+    let default_circuit: Symbol = symbol_short!("deflt5");
+    let result = client.try_verify_proof(&default_circuit, &public_inputs, &proof_bytes);
+    assert_eq!(result, Err(Ok(word_commit_contract::Error::NullifierReused)));
+}
+
+#[test]
+fn verify_proof_rejects_unknown_circuit_id() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, root) = wc_fixture(&env);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+
+    let client = register_word_commit_client(&env, &vk_bytes, &external_nullifier, &root, &admin);
+
+    let unknown_circuit: Symbol = symbol_short!("four4");
+    let result = client.try_verify_proof(&unknown_circuit, &public_inputs, &proof_bytes);
+    assert_eq!(result, Err(Ok(word_commit_contract::Error::VkNotSet)));
+}
+
+#[test]
+fn verify_proofs_batches_results_and_still_rejects_duplicate_nullifiers() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    let admin = Address::generate(&env);
+
+    let (vk_bytes, proof_bytes, public_inputs, root) = wc_fixture(&env);
+    let external_nullifier = BytesN::from_array(&env, &[7u8; 32]);
+
+    let client = register_word_commit_client(&env, &vk_bytes, &external_nullifier, &root, &admin);
+
+    let mut batch = soroban_sdk::Vec::new(&env);
+    batch.push_back((public_inputs.clone(), proof_bytes.clone()));
+    batch.push_back((public_inputs.clone(), proof_bytes.clone()));
+
+    let default_circuit: Symbol = symbol_short!("deflt5");
+    let results = client.verify_proofs(&default_circuit, &batch);
+
+    assert_eq!(results.len(), 2);
+    assert!(results.get(0).unwrap());
+    assert!(!results.get(1).unwrap()); // same nullifier already burned by the first item
+}
+
+// ── Main game contract: dictionary Merkle tree + RLN pure-compute paths ──
+//
+// `insert_word`, `verify_guesses_batch` and the RLN share-recovery path take
+// no ZK proof fixtures, so unlike the two wasm modules above these are
+// exercised directly against the game contract's own wasm, reconstructing
+// the same Poseidon2 incremental Merkle tree and Lagrange arithmetic
+// off-chain to check the on-chain result against.
+
+const GAME_MERKLE_DEPTH: u32 = 14;
+
+fn register_game_client<'a>(env: &'a Env, admin: &Address) -> ultrahonk_contract::Client<'a> {
+    let vk_bytes = Bytes::new(env);
+    let wc_vk_bytes = Bytes::new(env);
+    let contract_id = env.register(CONTRACT_WASM, (vk_bytes, wc_vk_bytes, admin.clone()));
     ultrahonk_contract::Client::new(env, &contract_id)
 }
 
+// Mirrors `word_leaf_value`'s Poseidon2 sponge for a word short enough (<=30
+// bytes) to absorb in a single `RATE`-sized block, which covers every word
+// used by these tests.
+fn word_leaf(env: &Env, word: &[u8]) -> U256 {
+    let mut chunk = [0u8; 32];
+    chunk[1..1 + word.len()].copy_from_slice(word);
+    chunk[1 + word.len()] = 0x01;
+    let chunk_u256 = U256::from_be_bytes(env, &Bytes::from_array(env, &chunk));
+    let field = Symbol::new(env, "BN254");
+    let mut inputs = soroban_sdk::Vec::new(env);
+    inputs.push_back(U256::from_u128(env, 0));
+    inputs.push_back(chunk_u256);
+    env.crypto().poseidon2_hash(&inputs, field)
+}
+
+// Mirrors `init_merkle_tree`'s zero-hash cascade: `zeros[l]` is the root of
+// an all-zero subtree of height `l`.
+fn zero_hashes(env: &Env) -> std::vec::Vec<U256> {
+    let field = Symbol::new(env, "BN254");
+    let mut zeros = std::vec::Vec::new();
+    let mut current = U256::from_u128(env, 0);
+    for _ in 0..GAME_MERKLE_DEPTH {
+        zeros.push(current.clone());
+        let mut inputs = soroban_sdk::Vec::new(env);
+        inputs.push_back(current.clone());
+        inputs.push_back(current.clone());
+        current = env.crypto().poseidon2_hash(&inputs, field.clone());
+    }
+    zeros
+}
+
+fn u256_to_bytesn(env: &Env, u: &U256) -> BytesN<32> {
+    let be = u.to_be_bytes();
+    let mut arr = [0u8; 32];
+    for i in 0..32u32 {
+        arr[i as usize] = be.get(i).unwrap_or(0);
+    }
+    BytesN::from_array(env, &arr)
+}
+
+fn bytesn_from_u128(env: &Env, v: u128) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    arr[16..32].copy_from_slice(&v.to_be_bytes());
+    BytesN::from_array(env, &arr)
+}
+
 #[test]
-fn verify_proof_succeeds() {
-    let vk_bytes_raw: &[u8] = include_bytes!("../circuit/target/vk");
-    let proof_bin: &[u8] = include_bytes!("../circuit/target/proof");
-    let pub_inputs_bin: &[u8] = include_bytes!("../circuit/target/public_inputs");
+fn insert_word_single_path_and_batch_verification_agree() {
+    let env = Env::default();
+    env.cost_estimate().budget().reset_unlimited();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = register_game_client(&env, &admin);
+
+    let apple = Bytes::from_slice(&env, b"apple");
+    let brave = Bytes::from_slice(&env, b"brave");
+    client.insert_word(&admin, &apple);
+    client.insert_word(&admin, &brave);
+
+    let leaf1 = word_leaf(&env, b"brave");
+    let zeros = zero_hashes(&env);
+
+    // Single-path proof for leaf 0 ("apple"): its sibling at the leaf level is
+    // leaf 1, then the zero hash at every level above, since only two of
+    // 2^GAME_MERKLE_DEPTH leaves are filled.
+    let mut path_elements = soroban_sdk::Vec::new(&env);
+    path_elements.push_back(u256_to_bytesn(&env, &leaf1));
+    for level in 1..GAME_MERKLE_DEPTH {
+        path_elements.push_back(u256_to_bytesn(&env, &zeros[level as usize]));
+    }
+    let mut path_indices = soroban_sdk::Vec::new(&env);
+    for _ in 0..GAME_MERKLE_DEPTH {
+        path_indices.push_back(0u32);
+    }
+
+    let nullifier = bytesn_from_u128(&env, 999);
+    client.verify_guess(
+        &apple,
+        &path_elements,
+        &path_indices,
+        &bytesn_from_u128(&env, 1),
+        &bytesn_from_u128(&env, 48),
+        &nullifier,
+    );
 
+    // Same path, wrong leaf content: "brave" doesn't hash to what the sibling
+    // was built for, so the reconstructed root must not match the stored one.
+    let result = client.try_verify_guess(
+        &brave,
+        &path_elements,
+        &path_indices,
+        &bytesn_from_u128(&env, 2),
+        &bytesn_from_u128(&env, 168),
+        &nullifier,
+    );
+    assert!(result.is_err());
+
+    // The batch path over both leaves must authenticate against the same
+    // root the single-path proof above just matched.
+    let mut guess_words = soroban_sdk::Vec::new(&env);
+    guess_words.push_back(apple.clone());
+    guess_words.push_back(brave.clone());
+
+    let mut bits = soroban_sdk::Vec::new(&env);
+    for _ in 0..GAME_MERKLE_DEPTH {
+        bits.push_back(true); // root down to height 1: always descend left
+    }
+    bits.push_back(true); // leaf 0
+    bits.push_back(true); // leaf 1
+    for _ in 1..GAME_MERKLE_DEPTH {
+        bits.push_back(false); // heights 1..depth-1: prune the all-zero right sibling
+    }
+
+    let mut hashes = soroban_sdk::Vec::new(&env);
+    for level in 1..GAME_MERKLE_DEPTH {
+        hashes.push_back(u256_to_bytesn(&env, &zeros[level as usize]));
+    }
+
+    let results = client.verify_guesses_batch(&guess_words, &bits, &hashes);
+    assert_eq!(results.len(), 2);
+    assert!(results.get(0).unwrap());
+    assert!(results.get(1).unwrap());
+}
+
+#[test]
+fn seven_rln_shares_under_one_nullifier_recover_planted_secret() {
     let env = Env::default();
     env.cost_estimate().budget().reset_unlimited();
-    assert_eq!(proof_bin.len(), PROOF_BYTES);
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let client = register_game_client(&env, &admin);
+
+    let apple = Bytes::from_slice(&env, b"apple");
+    client.insert_word(&admin, &apple);
+
+    // Lone leaf: its sibling is the zero hash at every level, including the
+    // leaf level itself.
+    let zeros = zero_hashes(&env);
+    let mut path_elements = soroban_sdk::Vec::new(&env);
+    for level in 0..GAME_MERKLE_DEPTH {
+        path_elements.push_back(u256_to_bytesn(&env, &zeros[level as usize]));
+    }
+    let mut path_indices = soroban_sdk::Vec::new(&env);
+    for _ in 0..GAME_MERKLE_DEPTH {
+        path_indices.push_back(0u32);
+    }
 
-    let vk_bytes = Bytes::from_slice(&env, vk_bytes_raw);
-    let proof_bytes = Bytes::from_slice(&env, proof_bin);
-    let public_inputs = Bytes::from_slice(&env, pub_inputs_bin);
+    let nullifier = bytesn_from_u128(&env, 7777);
+    // Degree-6 polynomial A(x) = 42 + x + x^2 + ... + x^6, planted secret
+    // a0 = 42. Shares (x, A(x)) for x = 1..=7 determine it exactly, matching
+    // RLN_MAX_GUESSES + 1.
+    let shares: [(u128, u128); 7] = [
+        (1, 48),
+        (2, 168),
+        (3, 1134),
+        (4, 5502),
+        (5, 19572),
+        (6, 56028),
+        (7, 137298),
+    ];
+    for (x, y) in shares {
+        client.verify_guess(
+            &apple,
+            &path_elements,
+            &path_indices,
+            &bytesn_from_u128(&env, x),
+            &bytesn_from_u128(&env, y),
+            &nullifier,
+        );
+    }
 
-    let client = register_client(&env, &vk_bytes);
-    client.verify_proof(&public_inputs, &proof_bytes);
+    let recovered = client.recover_secret(&nullifier);
+    assert_eq!(recovered, bytesn_from_u128(&env, 42));
 }
@@ -1,5 +1,7 @@
 #![no_std]
-use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Bytes, Env, Symbol};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec,
+};
 use ultrahonk_soroban_verifier::{UltraHonkVerifier, PROOF_BYTES};
 
 #[contract]
@@ -13,43 +15,242 @@ pub enum Error {
     ProofParseError = 2,
     VerificationFailed = 3,
     VkNotSet = 4,
+    NullifierReused = 5,
+    RootMismatch = 6,
+    SignatureInvalid = 7,
 }
 
 #[contractimpl]
 impl WordCommitVerifier {
-    fn key_vk() -> Symbol {
-        symbol_short!("vk")
+    fn key_default_circuit() -> Symbol {
+        symbol_short!("deflt5")
     }
 
-    /// Deploy with the verification key baked in.
-    pub fn __constructor(env: Env, vk_bytes: Bytes) -> Result<(), Error> {
-        env.storage().instance().set(&Self::key_vk(), &vk_bytes);
+    /// Keyed VK registry slot, one per circuit (e.g. one per word length or puzzle variant).
+    fn key_vk(circuit_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("vkreg"), circuit_id.clone())
+    }
+
+    fn key_external_nullifier() -> Symbol {
+        symbol_short!("extnull")
+    }
+
+    fn key_root() -> Symbol {
+        symbol_short!("root")
+    }
+
+    fn key_admin() -> Symbol {
+        symbol_short!("admin")
+    }
+
+    /// Storage key for a seen nullifier, scoped by the contract's external nullifier
+    /// so a proof from one round/game can't be replayed as if it were from another.
+    fn key_nullifier(external_nullifier: &BytesN<32>, nullifier_hash: &BytesN<32>) -> (Symbol, BytesN<32>, BytesN<32>) {
+        (symbol_short!("null"), external_nullifier.clone(), nullifier_hash.clone())
+    }
+
+    /// Read a fixed 32-byte field out of `public_inputs` at `field_index` (0-based,
+    /// each field occupying 32 bytes), matching the field layout UltraHonk emits.
+    fn extract_field(env: &Env, public_inputs: &Bytes, field_index: u32) -> BytesN<32> {
+        let mut buf = [0u8; 32];
+        let base = field_index * 32;
+        for i in 0..32u32 {
+            buf[i as usize] = public_inputs.get(base + i).unwrap_or(0);
+        }
+        BytesN::from_array(env, &buf)
+    }
+
+    /// Deploy with the verification key baked in, a per-round/per-game-id salt that
+    /// scopes nullifiers so the same secret can't be replayed across rounds, the
+    /// Poseidon Merkle root of the allowed-words dictionary the circuit proves
+    /// against, and the admin allowed to rotate that root between seasons.
+    pub fn __constructor(
+        env: Env,
+        vk_bytes: Bytes,
+        external_nullifier: BytesN<32>,
+        merkle_root: BytesN<32>,
+        admin: Address,
+    ) -> Result<(), Error> {
+        env.storage()
+            .instance()
+            .set(&Self::key_vk(&Self::key_default_circuit()), &vk_bytes);
+        env.storage()
+            .instance()
+            .set(&Self::key_external_nullifier(), &external_nullifier);
+        env.storage().instance().set(&Self::key_root(), &merkle_root);
+        env.storage().instance().set(&Self::key_admin(), &admin);
         Ok(())
     }
 
-    /// Verify an UltraHonk proof on-chain.
+    /// Rotate the committed dictionary root, e.g. between game seasons.
+    pub fn update_root(env: Env, new_root: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Self::key_admin())
+            .ok_or(Error::VkNotSet)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&Self::key_root(), &new_root);
+        Ok(())
+    }
+
+    /// Register (or replace) the VK for a circuit variant, e.g. a 4-, 5- or 6-letter
+    /// Wordle circuit, so one deployment can serve several puzzle variants.
+    pub fn register_vk(env: Env, circuit_id: Symbol, vk_bytes: Bytes) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&Self::key_admin())
+            .ok_or(Error::VkNotSet)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&Self::key_vk(&circuit_id), &vk_bytes);
+        Ok(())
+    }
+
+    /// Verify an UltraHonk proof against the VK registered for `circuit_id`, pinning
+    /// it to the committed dictionary root and rejecting replays of an already-seen
+    /// nullifier.
+    ///
+    /// `public_inputs` field 0 is the Merkle root the circuit proved the word's
+    /// membership against; field 1 is the semaphore-style `nullifier_hash`. Both are
+    /// checked only after the proof itself verifies, so a forged or stale proof can
+    /// never pin a bad root or burn a nullifier.
     pub fn verify_proof(
         env: Env,
+        circuit_id: Symbol,
         public_inputs: Bytes,
         proof_bytes: Bytes,
     ) -> Result<(), Error> {
-        if proof_bytes.len() as usize != PROOF_BYTES {
-            return Err(Error::ProofParseError);
+        Self::do_verify_proof(&env, &circuit_id, &public_inputs, &proof_bytes)
+    }
+
+    /// Bind a proof submission to a player's passkey before verifying it.
+    ///
+    /// Self-consistency between `pubkey` and `signature` alone doesn't stop a
+    /// front-runner: anyone can copy a stolen `(public_inputs, proof_bytes)` pair,
+    /// generate their own P-256 keypair, and sign it. So `public_inputs` field 2 is
+    /// `sha256(pubkey)` — the circuit commits the signing key at proof-generation
+    /// time — and a proof only passes here if the caller's `pubkey` hashes to that
+    /// committed value. A copied proof is therefore unusable under any key but the
+    /// one its real prover committed to. The message hash itself is taken over
+    /// `public_inputs || proof_bytes`, then checked against `pubkey` with
+    /// `secp256r1_verify`, which traps (no catchable `Error`) on a bad signature —
+    /// same as the channel signature checks elsewhere in this repo. Runs both
+    /// passkey checks first, then the existing `UltraHonkVerifier` + nullifier path,
+    /// so a forged or unbound signature never gets the chance to burn a nullifier.
+    pub fn verify_proof_signed(
+        env: Env,
+        circuit_id: Symbol,
+        public_inputs: Bytes,
+        proof_bytes: Bytes,
+        pubkey: BytesN<65>,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        let pubkey_bytes: Bytes = pubkey.clone().into();
+        let pubkey_hash = env.crypto().sha256(&pubkey_bytes).to_bytes();
+        let committed_pubkey_hash = Self::extract_field(&env, &public_inputs, 2);
+        if pubkey_hash != committed_pubkey_hash {
+            return Err(Error::SignatureInvalid);
         }
 
+        let mut message = Bytes::new(&env);
+        message.append(&public_inputs);
+        message.append(&proof_bytes);
+        let digest = env.crypto().sha256(&message);
+
+        env.crypto()
+            .secp256r1_verify(&pubkey, &digest.to_bytes(), &signature);
+
+        Self::do_verify_proof(&env, &circuit_id, &public_inputs, &proof_bytes)
+    }
+
+    /// Verify every `(public_inputs, proof_bytes)` pair in `batch` against the VK
+    /// registered for `circuit_id`, parsing that VK into an `UltraHonkVerifier` exactly
+    /// once and reusing it across the whole batch instead of re-parsing it per proof.
+    /// Each item still gets its own root check and nullifier check, so a batch can't
+    /// smuggle a duplicate submission past the per-item replay guard. Returns one
+    /// `bool` per input item, in order, rather than failing the whole batch on the
+    /// first bad proof.
+    pub fn verify_proofs(
+        env: Env,
+        circuit_id: Symbol,
+        batch: Vec<(Bytes, Bytes)>,
+    ) -> Result<Vec<bool>, Error> {
         let vk_bytes: Bytes = env
             .storage()
             .instance()
-            .get(&Self::key_vk())
+            .get(&Self::key_vk(&circuit_id))
             .ok_or(Error::VkNotSet)?;
+        let verifier = UltraHonkVerifier::new(&env, &vk_bytes).map_err(|_| Error::VkParseError)?;
 
-        let verifier =
-            UltraHonkVerifier::new(&env, &vk_bytes).map_err(|_| Error::VkParseError)?;
+        let mut results = Vec::new(&env);
+        for (public_inputs, proof_bytes) in batch.iter() {
+            let ok = Self::verify_one(&env, &verifier, &public_inputs, &proof_bytes).is_ok();
+            results.push_back(ok);
+        }
+        Ok(results)
+    }
+
+    fn do_verify_proof(
+        env: &Env,
+        circuit_id: &Symbol,
+        public_inputs: &Bytes,
+        proof_bytes: &Bytes,
+    ) -> Result<(), Error> {
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&Self::key_vk(circuit_id))
+            .ok_or(Error::VkNotSet)?;
+        let verifier = UltraHonkVerifier::new(env, &vk_bytes).map_err(|_| Error::VkParseError)?;
+
+        Self::verify_one(env, &verifier, public_inputs, proof_bytes)
+    }
+
+    /// Shared tail of proof verification: run the UltraHonk check against an
+    /// already-constructed verifier, then pin the dictionary root and enforce the
+    /// nullifier replay guard. Used by both the single-proof and batch entry points
+    /// so the VK only needs to be parsed once per call.
+    fn verify_one(
+        env: &Env,
+        verifier: &UltraHonkVerifier,
+        public_inputs: &Bytes,
+        proof_bytes: &Bytes,
+    ) -> Result<(), Error> {
+        if proof_bytes.len() as usize != PROOF_BYTES {
+            return Err(Error::ProofParseError);
+        }
 
         verifier
-            .verify(&proof_bytes, &public_inputs)
+            .verify(proof_bytes, public_inputs)
             .map_err(|_| Error::VerificationFailed)?;
 
+        let stored_root: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&Self::key_root())
+            .ok_or(Error::VkNotSet)?;
+        let root_from_pi = Self::extract_field(env, public_inputs, 0);
+        if stored_root != root_from_pi {
+            return Err(Error::RootMismatch);
+        }
+
+        let external_nullifier: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&Self::key_external_nullifier())
+            .ok_or(Error::VkNotSet)?;
+        let nullifier_hash = Self::extract_field(env, public_inputs, 1);
+
+        let null_key = Self::key_nullifier(&external_nullifier, &nullifier_hash);
+        if env.storage().persistent().has(&null_key) {
+            return Err(Error::NullifierReused);
+        }
+        env.storage().persistent().set(&null_key, &true);
+        env.storage().persistent().extend_ttl(&null_key, 20000, 20000);
+
         Ok(())
     }
 }